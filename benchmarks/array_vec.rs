@@ -1,5 +1,4 @@
 #![allow(missing_docs)]
-#![feature(array_map, min_const_generics)]
 
 use criterion::{
     black_box, criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup,
@@ -0,0 +1,99 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stack_based_vec::{ArrayArena, Handle};
+
+const CAPACITY: usize = 8;
+
+#[derive(arbitrary::Arbitrary, Debug)]
+enum Op {
+    Insert(u16),
+    Remove(usize),
+    Get(usize),
+    Get2Mut(usize, usize),
+    Forge(usize, u32),
+    Retain(u16),
+    Clear,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut arena: ArrayArena<u16, CAPACITY> = ArrayArena::new();
+    // Every handle ever issued, with the value it refers to while it is live
+    let mut issued: Vec<(Handle, Option<u16>)> = Vec::new();
+
+    for op in ops {
+        match op {
+            Op::Insert(value) => {
+                if let Ok(handle) = arena.insert(value) {
+                    assert!(issued.iter().all(|&(h, _)| h != handle));
+                    issued.push((handle, Some(value)));
+                }
+            }
+            Op::Remove(i) => {
+                if let Some((handle, live)) = pick(&mut issued, i) {
+                    assert_eq!(arena.remove(*handle), live.take());
+                }
+            }
+            Op::Get(i) => {
+                if let Some((handle, live)) = pick(&mut issued, i) {
+                    assert_eq!(arena.get(*handle), live.as_ref());
+                }
+            }
+            Op::Get2Mut(a, b) => {
+                if issued.is_empty() {
+                    continue;
+                }
+                let (a, b) = (a % issued.len(), b % issued.len());
+                let (ha, hb) = (issued[a].0, issued[b].0);
+                let both_live = issued[a].1.is_some() && issued[b].1.is_some();
+                match arena.get2_mut(ha, hb) {
+                    Some((x, y)) => {
+                        assert!(both_live && ha.index() != hb.index());
+                        core::mem::swap(x, y);
+                        let (va, vb) = (issued[a].1, issued[b].1);
+                        issued[a].1 = vb;
+                        issued[b].1 = va;
+                    }
+                    None => assert!(!both_live || ha.index() == hb.index()),
+                }
+            }
+            Op::Forge(index, generation) => {
+                let handle = Handle::new(index, generation);
+                let live = issued
+                    .iter()
+                    .find(|&&(h, _)| h == handle)
+                    .and_then(|&(_, v)| v);
+                assert_eq!(arena.get(handle).copied(), live);
+            }
+            Op::Retain(m) => {
+                let m = m.max(1);
+                arena.retain(|_, value| *value % m != 0);
+                for (_, live) in &mut issued {
+                    if matches!(live, Some(value) if *value % m == 0) {
+                        *live = None;
+                    }
+                }
+            }
+            Op::Clear => {
+                arena.clear();
+                issued.iter_mut().for_each(|(_, live)| *live = None);
+            }
+        }
+        assert_eq!(
+            arena.len(),
+            issued.iter().filter(|(_, live)| live.is_some()).count()
+        );
+        for &(handle, live) in &issued {
+            assert_eq!(arena.get(handle).copied(), live);
+        }
+    }
+});
+
+fn pick(issued: &mut [(Handle, Option<u16>)], i: usize) -> Option<&mut (Handle, Option<u16>)> {
+    if issued.is_empty() {
+        None
+    } else {
+        let len = issued.len();
+        issued.get_mut(i % len)
+    }
+}
@@ -39,7 +39,7 @@ fn push(data: &Data, v: &mut ArrayVec<i32, CAPACITY>) {
 }
 
 fn truncate(data: &Data, v: &mut ArrayVec<i32, CAPACITY>) {
-    let original = *v;
+    let original = v.clone();
     v.truncate(data.truncate);
     if let Some(rslt) = v.get(0..data.truncate) {
         assert_eq!(rslt, &original[0..data.truncate]);
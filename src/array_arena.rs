@@ -0,0 +1,588 @@
+//! A fixed-capacity generational arena whose handles detect stale accesses.
+
+use core::{
+    fmt,
+    iter::{Enumerate, FusedIterator, Zip},
+    mem::{self, MaybeUninit},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+/// Marks the `next` field of an occupied slot.
+const OCCUPIED: usize = usize::MAX;
+
+/// Identifies a value in an [`ArrayArena`] by its slot index and the
+/// generation of the slot when the value was inserted.
+///
+/// Removing a value bumps the generation of its slot, so a handle to it is
+/// rejected even after the slot is reused.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+impl Handle {
+    /// Constructs a handle from its parts.
+    #[inline]
+    pub const fn new(index: usize, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Returns the generation of the slot when the value was inserted.
+    #[inline]
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+
+    /// Returns the index of the slot, which is less than the capacity of the
+    /// arena.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    generation: u32,
+    /// [`OCCUPIED`], or the next vacant slot of the free list
+    next: usize,
+}
+
+/// A fixed-capacity arena stored inline, addressed by generational
+/// [`Handle`]s.
+///
+/// Values live in `MaybeUninit` storage like the elements of an
+/// [`ArrayVec`](crate::ArrayVec), and vacant slots form a free list, so
+/// inserting, removing and looking up values all take constant time.
+///
+/// A slot whose generation would overflow is retired instead of being
+/// reused, so handles are never confused, at the cost of one slot of
+/// capacity every `2^32` removals from it.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayArena;
+///
+/// let mut entities: ArrayArena<&str, 4> = ArrayArena::new();
+/// let player = entities.insert("player").unwrap();
+/// assert_eq!(entities.remove(player), Some("player"));
+///
+/// let enemy = entities.insert("enemy").unwrap();
+/// assert_eq!(enemy.index(), player.index());
+/// assert_eq!(entities.get(player), None);
+/// assert_eq!(entities.get(enemy), Some(&"enemy"));
+/// ```
+pub struct ArrayArena<T, const N: usize> {
+    data: MaybeUninit<[T; N]>,
+    slots: [Slot; N],
+    len: usize,
+    /// Head of the free list, or `grown` if it is empty
+    next_free: usize,
+    /// Number of slots that have ever been used
+    grown: usize,
+}
+
+impl<T, const N: usize> ArrayArena<T, N> {
+    // Constructors
+
+    /// Constructs a new, empty arena.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: MaybeUninit::uninit(),
+            slots: [Slot {
+                generation: 0,
+                next: 0,
+            }; N],
+            len: 0,
+            next_free: 0,
+            grown: 0,
+        }
+    }
+
+    // Methods
+
+    /// Returns the number of values the arena can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all values, invalidating every handle.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.drain().for_each(drop);
+    }
+
+    /// Returns `true` if `handle` refers to a value.
+    #[inline]
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.is_valid(handle)
+    }
+
+    /// Removes all values, returning them with their handles as an iterator
+    /// ordered by index. Every handle is invalidated.
+    ///
+    /// Values that the iterator does not yield are dropped with it. If it is
+    /// leaked, they stay in the arena.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayArena;
+    ///
+    /// let mut arena: ArrayArena<u8, 2> = ArrayArena::new();
+    /// let a = arena.insert(1).unwrap();
+    /// assert!(arena.drain().eq([(a, 1)]));
+    /// assert!(!arena.contains(a));
+    /// ```
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        Drain {
+            arena: self,
+            idx: 0,
+        }
+    }
+
+    /// Returns a reference to the value of `handle`.
+    #[inline]
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        if self.is_valid(handle) {
+            Some(unsafe { &*self.as_ptr().add(handle.index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns mutable references to the values of two handles at once, or
+    /// [`None`] if either is stale or both refer to the same slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayArena;
+    ///
+    /// let mut accounts: ArrayArena<u32, 4> = ArrayArena::new();
+    /// let a = accounts.insert(100).unwrap();
+    /// let b = accounts.insert(20).unwrap();
+    /// if let Some((from, to)) = accounts.get2_mut(a, b) {
+    ///     *from -= 30;
+    ///     *to += 30;
+    /// }
+    /// assert_eq!((accounts[a], accounts[b]), (70, 50));
+    /// assert!(accounts.get2_mut(a, a).is_none());
+    /// ```
+    #[inline]
+    pub fn get2_mut(&mut self, a: Handle, b: Handle) -> Option<(&mut T, &mut T)> {
+        if a.index == b.index || !self.is_valid(a) || !self.is_valid(b) {
+            return None;
+        }
+        let ptr = self.as_mut_ptr();
+        Some(unsafe { (&mut *ptr.add(a.index), &mut *ptr.add(b.index)) })
+    }
+
+    /// Returns a mutable reference to the value of `handle`.
+    #[inline]
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        if self.is_valid(handle) {
+            Some(unsafe { &mut *self.as_mut_ptr().add(handle.index) })
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a value, returning its handle, or returns the value back if
+    /// the arena is full.
+    pub fn insert(&mut self, value: T) -> Result<Handle, T> {
+        let index = self.next_free;
+        if index < self.grown {
+            self.next_free = self.slots[index].next;
+        } else if index < N {
+            self.grown += 1;
+            self.next_free = self.grown;
+        } else {
+            return Err(value);
+        }
+        unsafe { self.as_mut_ptr().add(index).write(value) };
+        let slot = &mut self.slots[index];
+        slot.next = OCCUPIED;
+        self.len += 1;
+        Ok(Handle {
+            index,
+            generation: slot.generation,
+        })
+    }
+
+    /// Returns `true` if the arena holds no values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the handles and values, ordered by index.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let grown = self.grown;
+        let values =
+            unsafe { slice::from_raw_parts(self.as_ptr() as *const MaybeUninit<T>, grown) };
+        Iter {
+            inner: self.slots[..grown].iter().enumerate().zip(values.iter()),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over the handles and mutable values, ordered by
+    /// index.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let grown = self.grown;
+        let values =
+            unsafe { slice::from_raw_parts_mut(self.as_mut_ptr() as *mut MaybeUninit<T>, grown) };
+        IterMut {
+            inner: self.slots[..grown]
+                .iter()
+                .enumerate()
+                .zip(values.iter_mut()),
+            len: self.len,
+        }
+    }
+
+    /// Returns the number of values.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Removes and returns the value of `handle`, or returns [`None`] if
+    /// `handle` is stale.
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        if !self.is_valid(handle) {
+            return None;
+        }
+        let value = unsafe { self.as_ptr().add(handle.index).read() };
+        let slot = &mut self.slots[handle.index];
+        if let Some(generation) = slot.generation.checked_add(1) {
+            slot.generation = generation;
+            slot.next = self.next_free;
+            self.next_free = handle.index;
+        } else {
+            // Retire the slot: it is vacant but not on the free list.
+            slot.next = self.grown;
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Retains only the values specified by the predicate, invalidating the
+    /// handles of the removed values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayArena;
+    ///
+    /// let mut arena: ArrayArena<u32, 4> = ArrayArena::new();
+    /// let handles: Vec<_> = (1..=4).map(|x| arena.insert(x).unwrap()).collect();
+    /// arena.retain(|_, x| *x % 2 == 0);
+    /// assert!(!arena.contains(handles[0]));
+    /// assert_eq!(arena[handles[1]], 2);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Handle, &mut T) -> bool,
+    {
+        for index in 0..self.grown {
+            let slot = self.slots[index];
+            if slot.next != OCCUPIED {
+                continue;
+            }
+            let handle = Handle {
+                index,
+                generation: slot.generation,
+            };
+            let value = unsafe { &mut *self.as_mut_ptr().add(index) };
+            if !f(handle, value) {
+                drop(self.remove(handle));
+            }
+        }
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut _
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const _
+    }
+
+    #[inline]
+    fn is_valid(&self, handle: Handle) -> bool {
+        match self.slots.get(handle.index) {
+            Some(slot) => slot.next == OCCUPIED && slot.generation == handle.generation,
+            None => false,
+        }
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayArena<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        for index in 0..self.grown {
+            let slot = self.slots[index];
+            clone.slots[index].generation = slot.generation;
+            clone.grown += 1;
+            if slot.next == OCCUPIED {
+                let value = unsafe { &*self.as_ptr().add(index) }.clone();
+                unsafe { clone.as_mut_ptr().add(index).write(value) };
+                clone.len += 1;
+            }
+            // Only mark the slot as occupied once its value is written, so a
+            // panicking `clone` drops exactly the values cloned so far.
+            clone.slots[index].next = slot.next;
+        }
+        clone.next_free = self.next_free;
+        clone
+    }
+}
+
+impl<T, const N: usize> Default for ArrayArena<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayArena<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T, const N: usize> Index<Handle> for ArrayArena<T, N> {
+    type Output = T;
+
+    /// Panics if `handle` is stale.
+    #[inline]
+    fn index(&self, handle: Handle) -> &T {
+        match self.get(handle) {
+            Some(value) => value,
+            None => panic!("stale handle"),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<Handle> for ArrayArena<T, N> {
+    /// Panics if `handle` is stale.
+    #[inline]
+    fn index_mut(&mut self, handle: Handle) -> &mut T {
+        match self.get_mut(handle) {
+            Some(value) => value,
+            None => panic!("stale handle"),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayArena<T, N> {
+    type IntoIter = Iter<'a, T>;
+    type Item = (Handle, &'a T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayArena<T, N> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = (Handle, &'a mut T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayArena<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// A draining iterator over the handles and values of an [`ArrayArena`],
+/// created by [`ArrayArena::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    arena: &'a mut ArrayArena<T, N>,
+    idx: usize,
+}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        /// Continues dropping the remaining values if one of them panics.
+        struct DropGuard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<T, const N: usize> Drop for DropGuard<'_, '_, T, N> {
+            fn drop(&mut self) {
+                self.0.for_each(drop);
+            }
+        }
+
+        while let Some(item) = self.next() {
+            let guard = DropGuard(self);
+            drop(item);
+            mem::forget(guard);
+        }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = (Handle, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.arena.grown {
+            let index = self.idx;
+            self.idx += 1;
+            let slot = self.arena.slots[index];
+            if slot.next == OCCUPIED {
+                let handle = Handle {
+                    index,
+                    generation: slot.generation,
+                };
+                return self.arena.remove(handle).map(|value| (handle, value));
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.arena.len, Some(self.arena.len))
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for Drain<'_, T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArenaDrain").field(&self.arena).finish()
+    }
+}
+
+macro_rules! iterator {
+    ($(#[$attr:meta])* $name:ident, $slice_iter:ident, $value:ty, $deref:ident) => {
+        $(#[$attr])*
+        pub struct $name<'a, T> {
+            inner: Zip<Enumerate<slice::Iter<'a, Slot>>, slice::$slice_iter<'a, MaybeUninit<T>>>,
+            len: usize,
+        }
+
+        impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                while let Some(((index, slot), value)) = self.inner.next_back() {
+                    if slot.next == OCCUPIED {
+                        self.len -= 1;
+                        let handle = Handle {
+                            index,
+                            generation: slot.generation,
+                        };
+                        return Some((handle, unsafe { value.$deref() }));
+                    }
+                }
+                None
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = (Handle, $value);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                for ((index, slot), value) in &mut self.inner {
+                    if slot.next == OCCUPIED {
+                        self.len -= 1;
+                        let handle = Handle {
+                            index,
+                            generation: slot.generation,
+                        };
+                        return Some((handle, unsafe { value.$deref() }));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+    };
+}
+
+iterator!(
+    /// An iterator over the handles and values of an [`ArrayArena`].
+    Iter,
+    Iter,
+    &'a T,
+    assume_init_ref
+);
+
+iterator!(
+    /// An iterator over the handles and mutable values of an [`ArrayArena`].
+    IterMut,
+    IterMut,
+    &'a mut T,
+    assume_init_mut
+);
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T> fmt::Debug for IterMut<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArenaIterMut")
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -0,0 +1,578 @@
+//! A fixed-capacity priority queue stored inline, and its comparators.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::FromIterator,
+    mem,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+use crate::{ArrayVec, CapacityError, Drain, IntoIter};
+
+/// Decides the order of the elements of an [`ArrayBinaryHeap`]: the greatest
+/// element according to `compare` is on top.
+///
+/// It is implemented by [`Max`], [`Min`] and closures.
+pub trait Compare<T> {
+    /// Compares `a` with `b`.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Puts the greatest element on top, like `std::collections::BinaryHeap`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Max;
+
+impl<T> Compare<T> for Max
+where
+    T: Ord,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Puts the smallest element on top.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Min;
+
+impl<T> Compare<T> for Min
+where
+    T: Ord,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+impl<T, F> Compare<T> for F
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// A fixed-capacity priority queue stored inline as a binary heap in an
+/// `ArrayVec<T, N>`.
+///
+/// It is a max-heap by default. Use [`Min`], `core::cmp::Reverse` elements
+/// or a closure for other orders.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::{ArrayBinaryHeap, Min};
+///
+/// let mut heap: ArrayBinaryHeap<u32, 4> = ArrayBinaryHeap::new();
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(5);
+/// assert_eq!(heap.pop(), Some(7));
+/// assert_eq!(heap.peek(), Some(&5));
+///
+/// let mut deadlines = ArrayBinaryHeap::<u32, 4, _>::with_comparator(Min);
+/// deadlines.push(30);
+/// deadlines.push(10);
+/// assert_eq!(deadlines.pop(), Some(10));
+/// ```
+#[derive(Clone)]
+pub struct ArrayBinaryHeap<T, const N: usize, C = Max> {
+    data: ArrayVec<T, N>,
+    cmp: C,
+}
+
+impl<T, const N: usize> ArrayBinaryHeap<T, N> {
+    /// Constructs a new, empty max-heap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayBinaryHeap;
+    ///
+    /// const EMPTY: ArrayBinaryHeap<i32, 4> = ArrayBinaryHeap::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: ArrayVec::new(),
+            cmp: Max,
+        }
+    }
+}
+
+impl<T, const N: usize, C> ArrayBinaryHeap<T, N, C>
+where
+    C: Compare<T>,
+{
+    // Constructors
+
+    /// Constructs a heap from the elements of `vec`, ordered by `cmp`.
+    ///
+    /// This takes linear time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec};
+    ///
+    /// let by_len = |a: &&str, b: &&str| a.len().cmp(&b.len());
+    /// let vec = ArrayVec::from_array(["ccc", "a", "bb"]);
+    /// let mut heap = ArrayBinaryHeap::from_vec_with_comparator(vec, by_len);
+    /// assert_eq!(heap.pop(), Some("ccc"));
+    /// ```
+    pub fn from_vec_with_comparator(vec: ArrayVec<T, N>, cmp: C) -> Self {
+        let mut heap = Self { data: vec, cmp };
+        heap.rebuild();
+        heap
+    }
+
+    /// Constructs a new, empty heap ordered by `cmp`.
+    #[inline]
+    pub const fn with_comparator(cmp: C) -> Self {
+        Self {
+            data: ArrayVec::new(),
+            cmp,
+        }
+    }
+
+    // Methods
+
+    /// Returns the elements in heap order, which is unspecified.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Returns the number of elements the heap can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    /// Removes all elements, returning them as an iterator in heap order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec};
+    ///
+    /// let mut heap: ArrayBinaryHeap<_, 2> = ArrayVec::from_array([1, 3]).into();
+    /// assert_eq!(heap.drain().count(), 2);
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        self.data.drain(..)
+    }
+
+    /// Returns the elements sorted so that the top of the heap comes last,
+    /// i.e. ascending according to the comparator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec, Min};
+    ///
+    /// let heap: ArrayBinaryHeap<_, 4> = ArrayVec::from_array([4, 1, 3, 2]).into();
+    /// assert_eq!(heap.into_sorted_vec(), [1, 2, 3, 4]);
+    ///
+    /// let heap: ArrayBinaryHeap<_, 4, Min> = ArrayVec::from_array([4, 1, 3, 2]).into();
+    /// assert_eq!(heap.into_sorted_vec(), [4, 3, 2, 1]);
+    /// ```
+    pub fn into_sorted_vec(mut self) -> ArrayVec<T, N> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.data.swap(0, end);
+            self.sift_down_range(0, end);
+        }
+        self.into_vec()
+    }
+
+    /// Returns the elements in heap order, which is unspecified.
+    #[inline]
+    pub fn into_vec(self) -> ArrayVec<T, N> {
+        self.data
+    }
+
+    /// Returns `true` if the heap holds no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns `true` if the heap is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.data.len() == N
+    }
+
+    /// Returns an iterator over the elements in heap order, which is
+    /// unspecified.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the element on top of the heap.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns a mutable reference to the element on top of the heap. The
+    /// heap is restored when the returned [`PeekMut`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec};
+    ///
+    /// let mut heap: ArrayBinaryHeap<_, 3> = ArrayVec::from_array([1, 5, 2]).into();
+    /// *heap.peek_mut().unwrap() = 0;
+    /// assert_eq!(heap.peek(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, N, C>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut {
+                heap: self,
+                sift: false,
+            })
+        }
+    }
+
+    /// Removes and returns the element on top of the heap.
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.data.pop()?;
+        if self.is_empty() {
+            return Some(last);
+        }
+        let top = mem::replace(&mut self.data[0], last);
+        self.sift_down_range(0, self.len());
+        Some(top)
+    }
+
+    /// Pushes an element onto the heap.
+    ///
+    /// Panics if the heap is full. See [`ArrayBinaryHeap::try_push`].
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        if self.try_push(element).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Pushes `element` and then pops the top of the heap, which is
+    /// `element` itself if it would be on top. This works even if the heap
+    /// is full.
+    ///
+    /// Keeping the `N` smallest elements of a stream in a max-heap only takes
+    /// a `push_pop` per element once the heap is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayBinaryHeap;
+    ///
+    /// let mut smallest: ArrayBinaryHeap<u32, 3> = ArrayBinaryHeap::new();
+    /// for x in [5, 1, 8, 3, 9, 2] {
+    ///     if smallest.is_full() {
+    ///         smallest.push_pop(x);
+    ///     } else {
+    ///         smallest.push(x);
+    ///     }
+    /// }
+    /// assert_eq!(smallest.into_sorted_vec(), [1, 2, 3]);
+    /// ```
+    pub fn push_pop(&mut self, element: T) -> T {
+        match self.data.first() {
+            Some(top) if self.cmp.compare(top, &element) == Ordering::Greater => {
+                let top = mem::replace(&mut self.data[0], element);
+                self.sift_down_range(0, self.len());
+                top
+            }
+            _ => element,
+        }
+    }
+
+    /// Pops the top of the heap and then pushes `element`, returning the old
+    /// top, or [`None`] if the heap was empty. This works even if the heap is
+    /// full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec};
+    ///
+    /// let mut heap: ArrayBinaryHeap<_, 2> = ArrayVec::from_array([1, 5]).into();
+    /// assert_eq!(heap.replace_top(3), Some(5));
+    /// assert_eq!(heap.into_sorted_vec(), [1, 3]);
+    /// ```
+    pub fn replace_top(&mut self, element: T) -> Option<T> {
+        if self.is_empty() {
+            self.push(element);
+            return None;
+        }
+        let top = mem::replace(&mut self.data[0], element);
+        self.sift_down_range(0, self.len());
+        Some(top)
+    }
+
+    /// Retains only the elements specified by the predicate.
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        self.data.retain(f);
+        if self.len() < len {
+            self.rebuild();
+        }
+    }
+
+    /// Pushes an element onto the heap, or returns it in a [`CapacityError`]
+    /// if the heap is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayBinaryHeap;
+    ///
+    /// let mut heap: ArrayBinaryHeap<i32, 1> = ArrayBinaryHeap::new();
+    /// assert!(heap.try_push(1).is_ok());
+    /// assert_eq!(heap.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        self.data.try_push(element)?;
+        self.sift_up(self.len() - 1);
+        Ok(())
+    }
+
+    /// Restores the heap property for all elements.
+    fn rebuild(&mut self) {
+        let len = self.len();
+        for pos in (0..len / 2).rev() {
+            self.sift_down_range(pos, len);
+        }
+    }
+
+    /// Moves the element at `pos` down until it is not smaller than its
+    /// children, considering only the elements before `end`.
+    ///
+    /// Elements are swapped one step at a time, so a panicking comparator
+    /// leaves every element in the heap exactly once.
+    fn sift_down_range(&mut self, mut pos: usize, end: usize) {
+        loop {
+            let mut child = 2 * pos + 1;
+            if child >= end {
+                break;
+            }
+            if child + 1 < end
+                && self.cmp.compare(&self.data[child + 1], &self.data[child]) == Ordering::Greater
+            {
+                child += 1;
+            }
+            if self.cmp.compare(&self.data[child], &self.data[pos]) != Ordering::Greater {
+                break;
+            }
+            self.data.swap(pos, child);
+            pos = child;
+        }
+    }
+
+    /// Moves the element at `pos` up until it is not greater than its parent.
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.cmp.compare(&self.data[pos], &self.data[parent]) != Ordering::Greater {
+                break;
+            }
+            self.data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+}
+
+impl<T, const N: usize, C> Default for ArrayBinaryHeap<T, N, C>
+where
+    C: Compare<T> + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_comparator(C::default())
+    }
+}
+
+impl<T, const N: usize, C> From<ArrayVec<T, N>> for ArrayBinaryHeap<T, N, C>
+where
+    C: Compare<T> + Default,
+{
+    #[inline]
+    fn from(vec: ArrayVec<T, N>) -> Self {
+        Self::from_vec_with_comparator(vec, C::default())
+    }
+}
+
+impl<T, const N: usize, C> From<ArrayBinaryHeap<T, N, C>> for ArrayVec<T, N> {
+    #[inline]
+    fn from(heap: ArrayBinaryHeap<T, N, C>) -> Self {
+        heap.data
+    }
+}
+
+/// Panics if the elements do not all fit.
+impl<T, const N: usize, C> FromIterator<T> for ArrayBinaryHeap<T, N, C>
+where
+    C: Compare<T> + Default,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from(iter.into_iter().collect::<ArrayVec<T, N>>())
+    }
+}
+
+impl<T, const N: usize, C> IntoIterator for ArrayBinaryHeap<T, N, C> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    /// Returns an iterator over the elements in heap order, which is
+    /// unspecified.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize, C> IntoIterator for &'a ArrayBinaryHeap<T, N, C>
+where
+    C: Compare<T>,
+{
+    type IntoIter = slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize, C> fmt::Debug for ArrayBinaryHeap<T, N, C>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter()).finish()
+    }
+}
+
+/// A mutable reference to the top of an [`ArrayBinaryHeap`], created by
+/// [`ArrayBinaryHeap::peek_mut`].
+///
+/// The element is moved back into place when this is dropped. Leaking it
+/// after modifying the element leaves the heap in an unspecified, but
+/// memory-safe, order.
+pub struct PeekMut<'a, T, const N: usize, C>
+where
+    C: Compare<T>,
+{
+    heap: &'a mut ArrayBinaryHeap<T, N, C>,
+    /// Whether the top may have been modified
+    sift: bool,
+}
+
+impl<T, const N: usize, C> PeekMut<'_, T, N, C>
+where
+    C: Compare<T>,
+{
+    /// Removes the element from the heap and returns it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayBinaryHeap, ArrayVec, PeekMut};
+    ///
+    /// let mut heap: ArrayBinaryHeap<_, 3> = ArrayVec::from_array([1, 5, 2]).into();
+    /// let top = heap.peek_mut().unwrap();
+    /// assert_eq!(PeekMut::pop(top), 5);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn pop(mut this: Self) -> T {
+        // The element is removed from the top, so there is nothing to sift
+        // when `this` is dropped.
+        this.sift = false;
+        match this.heap.pop() {
+            Some(top) => top,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, const N: usize, C> Deref for PeekMut<'_, T, N, C>
+where
+    C: Compare<T>,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.heap.data[0]
+    }
+}
+
+impl<T, const N: usize, C> DerefMut for PeekMut<'_, T, N, C>
+where
+    C: Compare<T>,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.sift = true;
+        &mut self.heap.data[0]
+    }
+}
+
+impl<T, const N: usize, C> Drop for PeekMut<'_, T, N, C>
+where
+    C: Compare<T>,
+{
+    fn drop(&mut self) {
+        if self.sift {
+            let len = self.heap.len();
+            self.heap.sift_down_range(0, len);
+        }
+    }
+}
+
+impl<T, const N: usize, C> fmt::Debug for PeekMut<'_, T, N, C>
+where
+    C: Compare<T>,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PeekMut").field(&self.heap.data[0]).finish()
+    }
+}
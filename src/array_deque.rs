@@ -0,0 +1,1109 @@
+//! A fixed-capacity double-ended queue and its iterators.
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Index, IndexMut, RangeBounds},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::{range_to_indices, ArrayVec, CapacityError, OutOfBoundsError};
+
+/// A fixed-capacity double-ended queue, implemented as a ring buffer.
+///
+/// Unlike [`ArrayVec`], elements can be added to and removed from both ends
+/// in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayDeque;
+///
+/// let mut queue: ArrayDeque<i32, 4> = ArrayDeque::new();
+/// queue.push_back(1);
+/// queue.push_back(2);
+/// queue.push_front(0);
+/// assert_eq!(queue.pop_front(), Some(0));
+/// assert_eq!(queue.pop_front(), Some(1));
+/// assert_eq!(queue.pop_back(), Some(2));
+/// assert_eq!(queue.pop_back(), None);
+/// ```
+pub struct ArrayDeque<T, const N: usize> {
+    data: MaybeUninit<[T; N]>,
+    /// Physical index of the front element
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayDeque<T, N> {
+    // Constructors
+
+    /// Constructs a new, empty `ArrayDeque`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let deque: ArrayDeque<i32, 10> = ArrayDeque::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: MaybeUninit::uninit(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements the deque can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements in the deque.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the deque has no room for more elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 1> = ArrayDeque::new();
+    /// deque.push_back(1);
+    /// assert!(deque.is_full());
+    /// ```
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// Panics if the deque is full.
+    #[inline]
+    pub fn push_back(&mut self, element: T) {
+        if self.try_push_back(element).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// Panics if the deque is full.
+    #[inline]
+    pub fn push_front(&mut self, element: T) {
+        if self.try_push_front(element).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Appends an element to the back of the deque. If the deque is full,
+    /// the front element is removed to make room, and returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 2> = ArrayDeque::new();
+    /// assert_eq!(deque.push_back_overwrite(1), None);
+    /// assert_eq!(deque.push_back_overwrite(2), None);
+    /// assert_eq!(deque.push_back_overwrite(3), Some(1));
+    /// assert_eq!(deque, [2, 3]);
+    /// ```
+    pub fn push_back_overwrite(&mut self, element: T) -> Option<T> {
+        if N == 0 {
+            return Some(element);
+        }
+
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        unsafe { self.write(self.len, element) };
+        self.len += 1;
+        evicted
+    }
+
+    /// Prepends an element to the front of the deque. If the deque is full,
+    /// the back element is removed to make room, and returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 2> = ArrayDeque::new();
+    /// assert_eq!(deque.push_front_overwrite(1), None);
+    /// assert_eq!(deque.push_front_overwrite(2), None);
+    /// assert_eq!(deque.push_front_overwrite(3), Some(1));
+    /// assert_eq!(deque, [3, 2]);
+    /// ```
+    pub fn push_front_overwrite(&mut self, element: T) -> Option<T> {
+        if N == 0 {
+            return Some(element);
+        }
+
+        let evicted = if self.is_full() {
+            self.pop_back()
+        } else {
+            None
+        };
+        self.head = self.wrap_sub(self.head, 1);
+        self.len += 1;
+        unsafe { self.write(0, element) };
+        evicted
+    }
+
+    /// Removes the last element of the deque and returns it, or [`None`] if
+    /// it is empty.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(unsafe { self.read(self.len) })
+        }
+    }
+
+    /// Removes the first element of the deque and returns it, or [`None`] if
+    /// it is empty.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let element = unsafe { self.read(0) };
+            self.head = self.to_physical(1);
+            self.len -= 1;
+            Some(element)
+        }
+    }
+
+    /// Returns a reference to the back element, or [`None`] if the deque is
+    /// empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a mutable reference to the back element, or [`None`] if the
+    /// deque is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a reference to the front element, or [`None`] if the deque is
+    /// empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the front element, or [`None`] if the
+    /// deque is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the element at `idx`, counted from the front,
+    /// or [`None`] if it is out of bounds.
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<&T> {
+        if idx < self.len {
+            Some(unsafe { &*self.as_ptr().add(self.to_physical(idx)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `idx`, counted from the
+    /// front, or [`None`] if it is out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T> {
+        if idx < self.len {
+            Some(unsafe { &mut *self.as_mut_ptr().add(self.to_physical(idx)) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the contents of the deque, in order, as a pair of slices.
+    ///
+    /// The second slice is empty if the contents are contiguous in memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 4> = ArrayDeque::new();
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// deque.push_front(0);
+    /// assert_eq!(deque.as_slices(), (&[0][..], &[1, 2][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            let ptr = self.as_ptr();
+            (
+                slice::from_raw_parts(ptr.add(front.0), front.1),
+                slice::from_raw_parts(ptr.add(back.0), back.1),
+            )
+        }
+    }
+
+    /// Returns the contents of the deque, in order, as a pair of mutable
+    /// slices.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            let ptr = self.as_mut_ptr();
+            (
+                slice::from_raw_parts_mut(ptr.add(front.0), front.1),
+                slice::from_raw_parts_mut(ptr.add(back.0), back.1),
+            )
+        }
+    }
+
+    /// Removes all elements from the deque.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+
+    /// Returns `true` if the deque contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        let (a, b) = self.as_slices();
+        a.contains(x) || b.contains(x)
+    }
+
+    /// Removes the elements in `range` from the deque, returning them as an
+    /// iterator.
+    ///
+    /// Whichever of the elements before and after the range are fewer is moved
+    /// to close the gap when the iterator is dropped.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayDeque, ArrayVec};
+    ///
+    /// let mut deque: ArrayDeque<i32, 8> = (0..6).collect();
+    /// let drained: ArrayVec<i32, 8> = deque.drain(1..4).collect();
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert_eq!(deque, [0, 4, 5]);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        match self.try_drain(range) {
+            Ok(drain) => drain,
+            Err(_) => panic!("drain range out of bounds"),
+        }
+    }
+
+    /// Returns a front-to-back iterator.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (a, b) = self.as_slices();
+        Iter {
+            front: a.iter(),
+            back: b.iter(),
+        }
+    }
+
+    /// Returns a front-to-back iterator that returns mutable references.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (a, b) = self.as_mut_slices();
+        IterMut {
+            front: a.iter_mut(),
+            back: b.iter_mut(),
+        }
+    }
+
+    /// Rearranges the contents of the deque so that they are contiguous in
+    /// memory, and returns them as a mutable slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 4> = ArrayDeque::new();
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    /// deque.make_contiguous().sort();
+    /// assert_eq!(deque.as_slices(), (&[1, 2][..], &[][..]));
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > N {
+            // The slots outside the contents are uninitialized, which is fine
+            // for `MaybeUninit`.
+            let buf = unsafe { &mut *(self.data.as_mut_ptr() as *mut [MaybeUninit<T>; N]) };
+            buf.rotate_left(self.head);
+            self.head = 0;
+        }
+
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr().add(self.head), self.len) }
+    }
+
+    /// Rotates the deque `n` places to the left, so that the element at index
+    /// `n` becomes the first element.
+    ///
+    /// This moves `min(n, len - n)` elements, or none if the deque is full.
+    ///
+    /// Panics if `n` is greater than the length of the deque.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 8> = (0..5).collect();
+    /// deque.rotate_left(2);
+    /// assert_eq!(deque, [2, 3, 4, 0, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "rotation amount out of bounds");
+        let k = self.len - n;
+        if n <= k {
+            unsafe { self.rotate_left_inner(n) }
+        } else {
+            unsafe { self.rotate_right_inner(k) }
+        }
+    }
+
+    /// Rotates the deque `n` places to the right, so that the element at
+    /// index 0 moves to index `n`.
+    ///
+    /// This moves `min(n, len - n)` elements, or none if the deque is full.
+    ///
+    /// Panics if `n` is greater than the length of the deque.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 8> = (0..5).collect();
+    /// deque.rotate_right(2);
+    /// assert_eq!(deque, [3, 4, 0, 1, 2]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "rotation amount out of bounds");
+        let k = self.len - n;
+        if n <= k {
+            unsafe { self.rotate_right_inner(n) }
+        } else {
+            unsafe { self.rotate_left_inner(k) }
+        }
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the
+    /// rest. Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        /// Drops the back slice if dropping the front slice panics.
+        struct Dropper<'a, T>(&'a mut [T]);
+
+        impl<T> Drop for Dropper<'_, T> {
+            fn drop(&mut self) {
+                unsafe { ptr::drop_in_place(self.0) }
+            }
+        }
+
+        if len >= self.len {
+            return;
+        }
+
+        let (front, back) = self.as_mut_slices();
+        let (front, back): (*mut [T], *mut [T]) = if len > front.len() {
+            let begin = len - front.len();
+            (&mut [][..], &mut back[begin..])
+        } else {
+            (&mut front[len..], back)
+        };
+        self.len = len;
+
+        unsafe {
+            let _back_dropper = Dropper(&mut *back);
+            ptr::drop_in_place(front);
+        }
+    }
+
+    /// Appends an element to the back of the deque, or returns it in a
+    /// [`CapacityError`] if the deque is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayDeque;
+    ///
+    /// let mut deque: ArrayDeque<i32, 1> = ArrayDeque::new();
+    /// assert!(deque.try_push_back(1).is_ok());
+    /// assert_eq!(deque.try_push_back(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push_back(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(element));
+        }
+
+        unsafe { self.write(self.len, element) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Prepends an element to the front of the deque, or returns it in a
+    /// [`CapacityError`] if the deque is full.
+    pub fn try_push_front(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(element));
+        }
+
+        self.head = self.wrap_sub(self.head, 1);
+        self.len += 1;
+        unsafe { self.write(0, element) };
+        Ok(())
+    }
+
+    /// Removes the elements in `range` from the deque, returning them as an
+    /// iterator, or returns an [`OutOfBoundsError`] if the range is out of
+    /// bounds.
+    pub fn try_drain<R>(&mut self, range: R) -> Result<Drain<'_, T, N>, OutOfBoundsError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let orig_len = self.len;
+        let (start, end) = range_to_indices(range, orig_len)?;
+
+        // Only keep the front part if the `Drain` is leaked.
+        self.len = start;
+
+        Ok(Drain {
+            deque: NonNull::from(self),
+            drain_start: start,
+            idx: start,
+            end,
+            drain_end: end,
+            orig_len,
+            _marker: PhantomData,
+        })
+    }
+
+    // Private helpers
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    /// Reads the element at logical index `idx`.
+    #[inline]
+    unsafe fn read(&self, idx: usize) -> T {
+        ptr::read(self.as_ptr().add(self.to_physical(idx)))
+    }
+
+    /// Moves `n` elements from the front to the back. `n` must not exceed
+    /// the length.
+    unsafe fn rotate_left_inner(&mut self, n: usize) {
+        if self.is_full() {
+            self.head = self.to_physical(n);
+            return;
+        }
+
+        for _ in 0..n {
+            let src = self.to_physical(0);
+            let dst = self.to_physical(self.len);
+            let ptr = self.as_mut_ptr();
+            ptr::copy_nonoverlapping(ptr.add(src), ptr.add(dst), 1);
+            self.head = self.to_physical(1);
+        }
+    }
+
+    /// Moves `n` elements from the back to the front. `n` must not exceed
+    /// the length.
+    unsafe fn rotate_right_inner(&mut self, n: usize) {
+        if self.is_full() {
+            self.head = self.wrap_sub(self.head, n);
+            return;
+        }
+
+        for _ in 0..n {
+            let src = self.to_physical(self.len - 1);
+            let dst = self.wrap_sub(self.head, 1);
+            let ptr = self.as_mut_ptr();
+            ptr::copy_nonoverlapping(ptr.add(src), ptr.add(dst), 1);
+            self.head = dst;
+        }
+    }
+
+    /// Returns the physical `(start, len)` of the two halves of the contents.
+    #[inline]
+    fn slice_ranges(&self) -> ((usize, usize), (usize, usize)) {
+        let head_room = N - self.head;
+        if self.len <= head_room {
+            ((self.head, self.len), (0, 0))
+        } else {
+            ((self.head, head_room), (0, self.len - head_room))
+        }
+    }
+
+    /// Maps a logical index to a physical one. `idx` must be at most `N`.
+    #[inline]
+    fn to_physical(&self, idx: usize) -> usize {
+        let i = self.head + idx;
+        if i >= N {
+            i - N
+        } else {
+            i
+        }
+    }
+
+    /// Subtracts `n` from the physical index `idx`, wrapping around.
+    #[inline]
+    fn wrap_sub(&self, idx: usize, n: usize) -> usize {
+        if idx >= n {
+            idx - n
+        } else {
+            idx + N - n
+        }
+    }
+
+    /// Writes `element` to logical index `idx`, without dropping the old
+    /// value.
+    #[inline]
+    unsafe fn write(&mut self, idx: usize, element: T) {
+        let idx = self.to_physical(idx);
+        ptr::write(self.as_mut_ptr().add(idx), element)
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayDeque<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut deque = Self::new();
+        for element in self {
+            unsafe { deque.write(deque.len, element.clone()) };
+            deque.len += 1;
+        }
+        deque
+    }
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayDeque<T, N> {
+    fn drop(&mut self) {
+        self.truncate(0);
+    }
+}
+
+impl<T, const N: usize> Eq for ArrayDeque<T, N> where T: Eq {}
+
+impl<T, const N: usize> Extend<T> for ArrayDeque<T, N> {
+    /// Extends the deque with the contents of an iterator, stopping once it is
+    /// full.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let remaining = N - self.len;
+        iter.into_iter()
+            .take(remaining)
+            .for_each(|element| self.push_back(element));
+    }
+}
+
+/// Converts an `ArrayVec` into an `ArrayDeque` without moving its elements.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::{ArrayDeque, ArrayVec};
+///
+/// let deque = ArrayDeque::from(ArrayVec::from_array([1, 2, 3]));
+/// assert_eq!(deque, [1, 2, 3]);
+/// ```
+impl<T, const N: usize> From<ArrayVec<T, N>> for ArrayDeque<T, N> {
+    fn from(vec: ArrayVec<T, N>) -> Self {
+        let vec = ManuallyDrop::new(vec);
+        Self {
+            data: unsafe { ptr::read(&vec.data) },
+            head: 0,
+            len: vec.len,
+        }
+    }
+}
+
+/// Converts an `ArrayDeque` into an `ArrayVec`.
+///
+/// The elements are only moved within the buffer if they do not already
+/// start at its beginning.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::{ArrayDeque, ArrayVec};
+///
+/// let mut deque: ArrayDeque<i32, 4> = ArrayDeque::new();
+/// deque.push_back(2);
+/// deque.push_front(1);
+/// assert_eq!(ArrayVec::from(deque), [1, 2]);
+/// ```
+impl<T, const N: usize> From<ArrayDeque<T, N>> for ArrayVec<T, N> {
+    fn from(deque: ArrayDeque<T, N>) -> Self {
+        let mut deque = ManuallyDrop::new(deque);
+        deque.make_contiguous();
+
+        let len = deque.len;
+        if deque.head != 0 {
+            unsafe {
+                let ptr = deque.as_mut_ptr();
+                ptr::copy(ptr.add(deque.head), ptr, len);
+            }
+        }
+
+        let mut vec = ArrayVec::new();
+        vec.data = unsafe { ptr::read(&deque.data) };
+        vec.len = len;
+        vec
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayDeque<T, N> {
+    /// Collects an iterator into an `ArrayDeque`.
+    ///
+    /// Panics if the iterator yields more than `N` elements.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut deque = Self::new();
+        for element in iter {
+            deque.push_back(element);
+        }
+        deque
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ArrayDeque<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, idx: usize) -> &T {
+        self.get(idx).expect("index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ArrayDeque<T, N> {
+    #[inline]
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        self.get_mut(idx).expect("index out of bounds")
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayDeque<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out of
+    /// the deque (from front to back).
+    #[inline]
+    fn into_iter(self) -> IntoIter<T, N> {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayDeque<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayDeque<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Ord for ArrayDeque<T, N>
+where
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<ArrayDeque<U, M>> for ArrayDeque<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &ArrayDeque<U, M>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for ArrayDeque<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.len() == M && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for ArrayDeque<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a == b)
+    }
+}
+
+impl<T, const N: usize> PartialOrd for ArrayDeque<T, N>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArrayDeque<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+/// A draining iterator over a range of an [`ArrayDeque`].
+///
+/// This is created by [`ArrayDeque::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    /// Will be used as &'a mut ArrayDeque in the destructor
+    deque: NonNull<ArrayDeque<T, N>>,
+    /// Logical index of the start of the drained range
+    drain_start: usize,
+    /// Logical index of the next element to yield from the front
+    idx: usize,
+    /// One past the logical index of the next element to yield from the back
+    end: usize,
+    /// Logical index of the end of the drained range
+    drain_end: usize,
+    /// Length of the deque before draining
+    orig_len: usize,
+    _marker: PhantomData<&'a mut ArrayDeque<T, N>>,
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.deque.as_ref().read(self.end) })
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for Drain<'_, T, N> {
+    fn drop(&mut self) {
+        /// Continues dropping the remaining elements in the `Drain`, then
+        /// closes the gap to restore the deque.
+        struct DropGuard<'r, 'a, T, const N: usize>(&'r mut Drain<'a, T, N>);
+
+        impl<T, const N: usize> Drop for DropGuard<'_, '_, T, N> {
+            fn drop(&mut self) {
+                self.0.for_each(drop);
+
+                let drain = &mut *self.0;
+                let deque = unsafe { drain.deque.as_mut() };
+                let head_len = drain.drain_start;
+                let tail_len = drain.orig_len - drain.drain_end;
+                let drain_len = drain.drain_end - drain.drain_start;
+
+                if drain_len == 0 {
+                    deque.len = drain.orig_len;
+                    return;
+                }
+
+                // Move whichever side of the gap is shorter.
+                unsafe {
+                    let ptr = deque.as_mut_ptr();
+                    if tail_len <= head_len {
+                        for i in 0..tail_len {
+                            let src = deque.to_physical(drain.drain_end + i);
+                            let dst = deque.to_physical(drain.drain_start + i);
+                            ptr::copy_nonoverlapping(ptr.add(src), ptr.add(dst), 1);
+                        }
+                    } else {
+                        for i in (0..head_len).rev() {
+                            let src = deque.to_physical(i);
+                            let dst = deque.to_physical(i + drain_len);
+                            ptr::copy_nonoverlapping(ptr.add(src), ptr.add(dst), 1);
+                        }
+                        deque.head = deque.to_physical(drain_len);
+                    }
+                }
+                deque.len = drain.orig_len - drain_len;
+            }
+        }
+
+        // exhaust self first
+        while let Some(item) = self.next() {
+            let guard = DropGuard(self);
+            drop(item);
+            mem::forget(guard);
+        }
+
+        DropGuard(self);
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.idx == self.end
+    }
+}
+
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            let element = unsafe { self.deque.as_ref().read(self.idx) };
+            self.idx += 1;
+            Some(element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for Drain<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DequeDrain")
+            .field("remaining", &(self.end - self.idx))
+            .finish()
+    }
+}
+
+unsafe impl<T, const N: usize> Send for Drain<'_, T, N> where T: Send {}
+unsafe impl<T, const N: usize> Sync for Drain<'_, T, N> where T: Sync {}
+
+/// An iterator that moves out of an [`ArrayDeque`].
+///
+/// This is created by the `into_iter` method on `ArrayDeque` (provided by the
+/// [`IntoIterator`] trait).
+#[derive(Clone)]
+pub struct IntoIter<T, const N: usize> {
+    deque: ArrayDeque<T, N>,
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.deque.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.deque.len()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for IntoIter<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DequeIntoIter").field(&self.deque).finish()
+    }
+}
+
+/// An iterator over the elements of an [`ArrayDeque`].
+///
+/// This is created by [`ArrayDeque::iter`].
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DequeIter")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
+
+/// A mutable iterator over the elements of an [`ArrayDeque`].
+///
+/// This is created by [`ArrayDeque::iter_mut`].
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> fmt::Debug for IterMut<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DequeIterMut")
+            .field(&self.front.as_slice())
+            .field(&self.back.as_slice())
+            .finish()
+    }
+}
@@ -0,0 +1,902 @@
+//! A fixed-capacity map stored inline, and its iterators and entries.
+
+use core::{
+    borrow::Borrow,
+    fmt,
+    iter::{self, FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem,
+    ops::Index,
+    slice,
+};
+
+use crate::{ArrayVec, CapacityError, IntoIter, Remaining};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// How an [`ArrayMap`] arranges its entries. Implemented by [`Unsorted`] and
+/// [`Sorted`].
+pub trait Order: sealed::Sealed {
+    #[doc(hidden)]
+    const SORTED: bool;
+}
+
+/// Finds a key of type `Q` among the entries of an [`ArrayMap`].
+///
+/// [`Unsorted`] maps compare keys with `Eq` and [`Sorted`] maps with `Ord`.
+pub trait Search<Q>: Order
+where
+    Q: ?Sized,
+{
+    /// Returns the index of the entry with `key`, or else the index at which
+    /// it would be inserted.
+    #[doc(hidden)]
+    fn search<K, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>;
+}
+
+/// Keeps the entries in no particular order and finds keys by linear scan.
+///
+/// Keys only need to implement `Eq`. This is usually the fastest choice for
+/// a few dozen entries.
+#[derive(Debug)]
+pub enum Unsorted {}
+
+impl sealed::Sealed for Unsorted {}
+
+impl Order for Unsorted {
+    const SORTED: bool = false;
+}
+
+impl<Q> Search<Q> for Unsorted
+where
+    Q: Eq + ?Sized,
+{
+    #[inline]
+    fn search<K, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        match entries.iter().position(|(k, _)| k.borrow() == key) {
+            Some(idx) => Ok(idx),
+            None => Err(entries.len()),
+        }
+    }
+}
+
+/// Keeps the entries sorted by key and finds keys by binary search.
+///
+/// Keys need to implement `Ord`, and iteration yields them in ascending
+/// order.
+#[derive(Debug)]
+pub enum Sorted {}
+
+impl sealed::Sealed for Sorted {}
+
+impl Order for Sorted {
+    const SORTED: bool = true;
+}
+
+impl<Q> Search<Q> for Sorted
+where
+    Q: Ord + ?Sized,
+{
+    #[inline]
+    fn search<K, V>(entries: &[(K, V)], key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+    {
+        entries.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+}
+
+/// An [`ArrayMap`] that keeps its entries sorted by key.
+pub type SortedArrayMap<K, V, const N: usize> = ArrayMap<K, V, N, Sorted>;
+
+/// A fixed-capacity map stored inline as an `ArrayVec<(K, V), N>`.
+///
+/// `O` chooses between linear scans over [`Unsorted`] entries, the default,
+/// and binary search over [`Sorted`] ones. Removing from an unsorted map
+/// moves the last entry into the gap.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayMap;
+///
+/// let mut ports: ArrayMap<&str, u16, 4> = ArrayMap::new();
+/// ports.insert("http", 80);
+/// ports.insert("ssh", 22);
+/// assert_eq!(ports.get("http"), Some(&80));
+/// assert_eq!(ports.insert("http", 8080), Some(80));
+/// assert_eq!(ports.remove("ssh"), Some(22));
+/// assert_eq!(ports.len(), 1);
+/// ```
+pub struct ArrayMap<K, V, const N: usize, O = Unsorted> {
+    entries: ArrayVec<(K, V), N>,
+    order: PhantomData<O>,
+}
+
+impl<K, V, const N: usize, O> ArrayMap<K, V, N, O>
+where
+    O: Order,
+{
+    // Constructors
+
+    /// Constructs a new, empty `ArrayMap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayMap;
+    ///
+    /// const EMPTY: ArrayMap<u8, u8, 4> = ArrayMap::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: ArrayVec::new(),
+            order: PhantomData,
+        }
+    }
+
+    /// Constructs a map from the pairs of `iter`, or fails if the distinct
+    /// keys do not all fit.
+    ///
+    /// A later pair replaces the value of an earlier one with the same key.
+    /// On overflow, the error holds the map so far and an iterator over the
+    /// pairs that were not inserted; its first pair is the one that did not
+    /// fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayMap;
+    ///
+    /// let map = ArrayMap::<char, i32, 2>::try_from_iter([('a', 1), ('b', 2), ('a', 3)]).unwrap();
+    /// assert_eq!(map.get(&'a'), Some(&3));
+    ///
+    /// let (map, rest) = ArrayMap::<char, i32, 2>::try_from_iter([('a', 1), ('b', 2), ('c', 3)])
+    ///     .unwrap_err()
+    ///     .element();
+    /// assert_eq!(map.len(), 2);
+    /// assert!(rest.eq([('c', 3)]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_from_iter<I>(
+        iter: I,
+    ) -> Result<Self, CapacityError<(Self, Remaining<(K, V), I::IntoIter>)>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        O: Search<K>,
+    {
+        let mut map = Self::new();
+        let mut iter = iter.into_iter();
+        while let Some((key, value)) = iter.next() {
+            if let Err(err) = map.try_insert(key, value) {
+                return Err(CapacityError::new((
+                    map,
+                    iter::once(err.element()).chain(iter),
+                )));
+            }
+        }
+        Ok(map)
+    }
+
+    // Methods
+
+    /// Returns the entries as a slice of key-value pairs.
+    #[inline]
+    pub const fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    /// Returns the number of entries the map can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns `true` if the map holds an entry for `key`.
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        O::search(&self.entries, key).is_ok()
+    }
+
+    /// Returns the entry for `key`, to inspect or modify it in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayMap;
+    ///
+    /// let mut counts: ArrayMap<char, u32, 8> = ArrayMap::new();
+    /// for c in "hello".chars() {
+    ///     *counts.entry(c).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts[&'h'], 1);
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N>
+    where
+        O: Search<K>,
+    {
+        match O::search(&self.entries, &key) {
+            Ok(idx) => Entry::Occupied(OccupiedEntry {
+                entries: &mut self.entries,
+                idx,
+                sorted: O::SORTED,
+            }),
+            Err(idx) => Entry::Vacant(VacantEntry {
+                entries: &mut self.entries,
+                key,
+                idx,
+            }),
+        }
+    }
+
+    /// Returns a reference to the value for `key`.
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Returns references to the stored key and the value for `key`.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        let idx = O::search(&self.entries, key).ok()?;
+        let (k, v) = &self.entries[idx];
+        Some((k, v))
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        let idx = O::search(&self.entries, key).ok()?;
+        Some(&mut self.entries[idx].1)
+    }
+
+    /// Inserts a value for `key`, returning the value it replaces.
+    ///
+    /// Panics if `key` is new and the map is full. See
+    /// [`ArrayMap::try_insert`].
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        O: Search<K>,
+    {
+        match self.try_insert(key, value) {
+            Ok(old) => old,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Returns the entries as a vector of key-value pairs.
+    #[inline]
+    pub fn into_inner(self) -> ArrayVec<(K, V), N> {
+        self.entries
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the map is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.entries.len() == N
+    }
+
+    /// Returns an iterator over the keys and values.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over the keys and mutable values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.entries.iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the keys.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            iter: self.entries.iter(),
+        }
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Removes the entry for `key`, returning its value.
+    #[inline]
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Removes the entry for `key`, returning the stored key and its value.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        O: Search<Q>,
+        Q: ?Sized,
+    {
+        let idx = O::search(&self.entries, key).ok()?;
+        Some(remove_at(&mut self.entries, idx, O::SORTED))
+    }
+
+    /// Retains only the entries specified by the predicate, keeping their
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArrayMap;
+    ///
+    /// let mut map: SortedArrayMap<u8, u8, 4> = (0..4).map(|i| (i, i * 10)).collect();
+    /// map.retain(|k, v| {
+    ///     *v += 1;
+    ///     k % 2 == 1
+    /// });
+    /// assert!(map.iter().eq([(&1, &11), (&3, &31)]));
+    /// ```
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+
+    /// Inserts a value for `key` and returns the value it replaces, or
+    /// returns the pair in a [`CapacityError`] if `key` is new and the map is
+    /// full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<u8, char, 1> = ArrayMap::new();
+    /// assert_eq!(map.try_insert(1, 'a'), Ok(None));
+    /// assert_eq!(map.try_insert(1, 'b'), Ok(Some('a')));
+    /// assert_eq!(map.try_insert(2, 'c').unwrap_err().element(), (2, 'c'));
+    /// ```
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>>
+    where
+        O: Search<K>,
+    {
+        match O::search(&self.entries, &key) {
+            Ok(idx) => Ok(Some(mem::replace(&mut self.entries[idx].1, value))),
+            Err(idx) => {
+                self.entries.try_insert(idx, (key, value))?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns an iterator over the values.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            iter: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable values.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut {
+            iter: self.entries.iter_mut(),
+        }
+    }
+}
+
+/// Removes the entry at `idx`, keeping the order of the others only if the
+/// map is sorted.
+#[inline]
+fn remove_at<K, V, const N: usize>(
+    entries: &mut ArrayVec<(K, V), N>,
+    idx: usize,
+    sorted: bool,
+) -> (K, V) {
+    if sorted {
+        entries.remove(idx)
+    } else {
+        entries.swap_remove(idx)
+    }
+}
+
+impl<K, V, const N: usize, O> Clone for ArrayMap<K, V, N, O>
+where
+    K: Clone,
+    V: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+            order: PhantomData,
+        }
+    }
+}
+
+impl<K, V, const N: usize, O> Default for ArrayMap<K, V, N, O>
+where
+    O: Order,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const N: usize, O> Eq for ArrayMap<K, V, N, O>
+where
+    O: Search<K>,
+    V: Eq,
+{
+}
+
+/// Panics if the distinct keys do not all fit. Use
+/// [`ArrayMap::try_from_iter`] to handle overflow instead.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayMap;
+///
+/// let map: ArrayMap<u8, char, 4> = [(1, 'a'), (2, 'b')].iter().copied().collect();
+/// assert_eq!(map[&2], 'b');
+/// ```
+impl<K, V, const N: usize, O> FromIterator<(K, V)> for ArrayMap<K, V, N, O>
+where
+    O: Search<K>,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(map) => map,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+}
+
+/// Panics if there is no entry for the key.
+impl<K, Q, V, const N: usize, O> Index<&Q> for ArrayMap<K, V, N, O>
+where
+    K: Borrow<Q>,
+    O: Search<Q>,
+    Q: ?Sized,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, const N: usize, O> IntoIterator for ArrayMap<K, V, N, O> {
+    type IntoIter = IntoIter<(K, V), N>;
+    type Item = (K, V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, O> IntoIterator for &'a ArrayMap<K, V, N, O>
+where
+    O: Order,
+{
+    type IntoIter = Iter<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const N: usize, O> IntoIterator for &'a mut ArrayMap<K, V, N, O>
+where
+    O: Order,
+{
+    type IntoIter = IterMut<'a, K, V>;
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Maps are equal if they hold the same keys with equal values, whatever
+/// their order and capacity.
+impl<K, V, const N: usize, const M: usize, O> PartialEq<ArrayMap<K, V, M, O>>
+    for ArrayMap<K, V, N, O>
+where
+    O: Search<K>,
+    V: PartialEq,
+{
+    fn eq(&self, other: &ArrayMap<K, V, M, O>) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K, V, const N: usize, O> fmt::Debug for ArrayMap<K, V, N, O>
+where
+    K: fmt::Debug,
+    O: Order,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// A view into a single entry of an [`ArrayMap`], created by
+/// [`ArrayMap::entry`].
+pub enum Entry<'a, K, V, const N: usize> {
+    /// An entry that holds a value.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    /// An entry without a value.
+    Vacant(VacantEntry<'a, K, V, N>),
+}
+
+impl<'a, K, V, const N: usize> Entry<'a, K, V, N> {
+    /// Calls `f` on the value if the entry is occupied.
+    #[inline]
+    pub fn and_modify<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Returns the key of the entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Returns the value, inserting the default value if the entry is
+    /// vacant.
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Returns the value, inserting `default` if the entry is vacant.
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Returns the value, inserting the result of `default` if the entry is
+    /// vacant.
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    #[inline]
+    pub fn or_insert_with<F>(self, default: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Returns the value, inserting the result of `default` if the entry is
+    /// vacant, or fails if the entry is vacant and the map is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayMap;
+    ///
+    /// let mut map: ArrayMap<u8, u8, 1> = ArrayMap::new();
+    /// *map.entry(1).or_try_insert_with(|| 0).unwrap() += 1;
+    /// assert!(map.entry(2).or_try_insert_with(|| 0).is_err());
+    /// assert_eq!(map[&1], 1);
+    /// ```
+    pub fn or_try_insert_with<F>(self, default: F) -> Result<&'a mut V, CapacityError<(K, V)>>
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.try_insert(default()),
+        }
+    }
+}
+
+/// An occupied entry of an [`ArrayMap`].
+pub struct OccupiedEntry<'a, K, V, const N: usize> {
+    entries: &'a mut ArrayVec<(K, V), N>,
+    idx: usize,
+    sorted: bool,
+}
+
+impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+    /// Returns a reference to the value.
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.entries[self.idx].1
+    }
+
+    /// Returns a mutable reference to the value.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries[self.idx].1
+    }
+
+    /// Replaces the value, returning the old one.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Returns a mutable reference to the value that lives as long as the
+    /// map borrow.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries[self.idx].1
+    }
+
+    /// Returns the key of the entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.entries[self.idx].0
+    }
+
+    /// Removes the entry, returning its value.
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry, returning its key and value.
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        remove_at(self.entries, self.idx, self.sorted)
+    }
+}
+
+impl<K, V, const N: usize> fmt::Debug for OccupiedEntry<'_, K, V, N>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+/// A vacant entry of an [`ArrayMap`].
+pub struct VacantEntry<'a, K, V, const N: usize> {
+    entries: &'a mut ArrayVec<(K, V), N>,
+    key: K,
+    /// Index at which the entry is inserted
+    idx: usize,
+}
+
+impl<'a, K, V, const N: usize> VacantEntry<'a, K, V, N> {
+    /// Inserts `value` for the key and returns a mutable reference to it.
+    ///
+    /// Panics if the map is full. See [`VacantEntry::try_insert`].
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        match self.try_insert(value) {
+            Ok(value) => value,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Returns the key that would be inserted.
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Returns the key that would be inserted.
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts `value` for the key and returns a mutable reference to it, or
+    /// returns the pair in a [`CapacityError`] if the map is full.
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        self.entries.try_insert(self.idx, (self.key, value))?;
+        Ok(&mut self.entries[self.idx].1)
+    }
+}
+
+impl<K, V, const N: usize> fmt::Debug for VacantEntry<'_, K, V, N>
+where
+    K: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(&self.key).finish()
+    }
+}
+
+impl<K, V, const N: usize> fmt::Debug for Entry<'_, K, V, N>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Entry::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Entry::Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+macro_rules! iterator {
+    (
+        $(#[$attr:meta])*
+        $name:ident<$($lt:lifetime),*>, $inner:ty, $item:ty, |$entry:pat_param| $map:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $name<$($lt,)* K, V> {
+            iter: $inner,
+        }
+
+        impl<K, V> DoubleEndedIterator for $name<'_, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.iter.next_back().map(|$entry| $map)
+            }
+        }
+
+        impl<K, V> ExactSizeIterator for $name<'_, K, V> {}
+
+        impl<K, V> FusedIterator for $name<'_, K, V> {}
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map(|$entry| $map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+        }
+
+        impl<K, V> fmt::Debug for $name<'_, K, V>
+        where
+            K: fmt::Debug,
+            V: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(concat!("Map", stringify!($name)))
+                    .field(&self.iter.as_slice())
+                    .finish()
+            }
+        }
+    };
+}
+
+iterator! {
+    /// An iterator over the keys and values of an [`ArrayMap`].
+    Iter<'a>, slice::Iter<'a, (K, V)>, (&'a K, &'a V), |(k, v)| (k, v)
+}
+
+iterator! {
+    /// An iterator over the keys and mutable values of an [`ArrayMap`].
+    IterMut<'a>, slice::IterMut<'a, (K, V)>, (&'a K, &'a mut V), |(k, v)| (&*k, v)
+}
+
+iterator! {
+    /// An iterator over the keys of an [`ArrayMap`].
+    Keys<'a>, slice::Iter<'a, (K, V)>, &'a K, |(k, _)| k
+}
+
+iterator! {
+    /// An iterator over the values of an [`ArrayMap`].
+    Values<'a>, slice::Iter<'a, (K, V)>, &'a V, |(_, v)| v
+}
+
+iterator! {
+    /// An iterator over the mutable values of an [`ArrayMap`].
+    ValuesMut<'a>, slice::IterMut<'a, (K, V)>, &'a mut V, |(_, v)| v
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K, V> Clone for Keys<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<K, V> Clone for Values<'_, K, V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
@@ -0,0 +1,768 @@
+//! A fixed-capacity set stored inline, and its iterators.
+
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    iter::{self, FromIterator, FusedIterator, Peekable},
+    slice,
+};
+
+use crate::{
+    array_map::{self, ArrayMap, Order, Search, Sorted, Unsorted},
+    CapacityError, IntoIter as VecIntoIter, Remaining,
+};
+
+/// An [`ArraySet`] that keeps its elements sorted.
+pub type SortedArraySet<T, const N: usize> = ArraySet<T, N, Sorted>;
+
+/// A fixed-capacity set stored inline.
+///
+/// It is an [`ArrayMap`] without values, and `O` chooses the same way between
+/// linear scans over [`Unsorted`] elements, which only need `Eq`, and binary
+/// search over [`Sorted`] ones, which need `Ord`.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArraySet;
+///
+/// let mut tags: ArraySet<&str, 4> = ArraySet::new();
+/// assert_eq!(tags.insert("red"), Ok(true));
+/// assert_eq!(tags.insert("red"), Ok(false));
+/// tags.insert("blue").unwrap();
+/// assert!(tags.contains("blue"));
+/// assert!(tags.remove("red"));
+/// assert_eq!(tags.len(), 1);
+/// ```
+pub struct ArraySet<T, const N: usize, O = Unsorted> {
+    map: ArrayMap<T, (), N, O>,
+}
+
+impl<T, const N: usize, O> ArraySet<T, N, O>
+where
+    O: Order,
+{
+    // Constructors
+
+    /// Constructs a new, empty `ArraySet`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// const EMPTY: ArraySet<u8, 4> = ArraySet::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            map: ArrayMap::new(),
+        }
+    }
+
+    /// Constructs a set from the elements of `iter`, or fails if the distinct
+    /// elements do not all fit.
+    ///
+    /// On overflow, the error holds the set so far and an iterator over the
+    /// elements that were not inserted; its first element is the one that
+    /// did not fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// let set = ArraySet::<u8, 2>::try_from_iter([1, 2, 1, 2]).unwrap();
+    /// assert_eq!(set.len(), 2);
+    ///
+    /// let (set, rest) = ArraySet::<u8, 2>::try_from_iter([1, 2, 3, 1]).unwrap_err().element();
+    /// assert_eq!(set.len(), 2);
+    /// assert!(rest.eq([3, 1]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_from_iter<I>(
+        iter: I,
+    ) -> Result<Self, CapacityError<(Self, Remaining<T, I::IntoIter>)>>
+    where
+        I: IntoIterator<Item = T>,
+        O: Search<T>,
+    {
+        let mut set = Self::new();
+        let mut iter = iter.into_iter();
+        while let Some(value) = iter.next() {
+            if let Err(err) = set.insert(value) {
+                return Err(CapacityError::new((
+                    set,
+                    iter::once(err.element()).chain(iter),
+                )));
+            }
+        }
+        Ok(set)
+    }
+
+    // Methods
+
+    /// Returns the number of elements the set can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns `true` if the set holds an element equal to `value`.
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        O: Search<Q>,
+        Q: ?Sized,
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Returns an iterator over the elements of `self` that are not in
+    /// `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// let a: ArraySet<u8, 4> = [1, 2, 3].iter().copied().collect();
+    /// let b: ArraySet<u8, 4> = [2, 3, 4].iter().copied().collect();
+    /// assert!(a.difference(&b).eq(&[1]));
+    /// ```
+    #[inline]
+    pub fn difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ArraySet<T, M, O>,
+    ) -> Difference<'a, T, M, O>
+    where
+        O: Search<T>,
+    {
+        Difference {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Returns a reference to the stored element equal to `value`.
+    #[inline]
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        O: Search<Q>,
+        Q: ?Sized,
+        T: Borrow<Q>,
+    {
+        self.map.get_key_value(value).map(|(k, _)| k)
+    }
+
+    /// Inserts `value`, returning `Ok(true)` if it was not present and
+    /// `Ok(false)` if it was, in which case the set keeps the stored element.
+    ///
+    /// Returns `value` in a [`CapacityError`] if it is not present and the
+    /// set is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// let mut set: ArraySet<u8, 1> = ArraySet::new();
+    /// assert_eq!(set.insert(1), Ok(true));
+    /// assert_eq!(set.insert(1), Ok(false));
+    /// assert_eq!(set.insert(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) -> Result<bool, CapacityError<T>>
+    where
+        O: Search<T>,
+    {
+        match self.map.entry(value) {
+            array_map::Entry::Occupied(_) => Ok(false),
+            array_map::Entry::Vacant(entry) => match entry.try_insert(()) {
+                Ok(()) => Ok(true),
+                Err(err) => Err(CapacityError::new(err.element().0)),
+            },
+        }
+    }
+
+    /// Returns an iterator over the elements found in both `self` and
+    /// `other`, in the order of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArraySet;
+    ///
+    /// let a: SortedArraySet<u8, 4> = [3, 1, 2].iter().copied().collect();
+    /// let b: SortedArraySet<u8, 4> = [2, 3, 4].iter().copied().collect();
+    /// assert!(a.intersection(&b).eq(&[2, 3]));
+    /// ```
+    #[inline]
+    pub fn intersection<'a, const M: usize>(
+        &'a self,
+        other: &'a ArraySet<T, M, O>,
+    ) -> Intersection<'a, T, M, O>
+    where
+        O: Search<T>,
+    {
+        Intersection {
+            iter: self.iter(),
+            other,
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have no elements in common.
+    #[inline]
+    pub fn is_disjoint<const M: usize>(&self, other: &ArraySet<T, M, O>) -> bool
+    where
+        O: Search<T>,
+    {
+        self.intersection(other).next().is_none()
+    }
+
+    /// Returns `true` if the set holds no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns `true` if the set is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Returns `true` if every element of `self` is in `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// let small: ArraySet<u8, 2> = [1, 2].iter().copied().collect();
+    /// let large: ArraySet<u8, 4> = [3, 2, 1].iter().copied().collect();
+    /// assert!(small.is_subset(&large));
+    /// assert!(!large.is_subset(&small));
+    /// ```
+    #[inline]
+    pub fn is_subset<const M: usize>(&self, other: &ArraySet<T, M, O>) -> bool
+    where
+        O: Search<T>,
+    {
+        self.len() <= other.len() && self.iter().all(|value| other.contains(value))
+    }
+
+    /// Returns `true` if every element of `other` is in `self`.
+    #[inline]
+    pub fn is_superset<const M: usize>(&self, other: &ArraySet<T, M, O>) -> bool
+    where
+        O: Search<T>,
+    {
+        other.is_subset(self)
+    }
+
+    /// Returns an iterator over the elements, in ascending order if the set
+    /// is sorted.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            iter: self.map.keys(),
+        }
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Removes the element equal to `value`, returning `true` if it was
+    /// present.
+    #[inline]
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        O: Search<Q>,
+        Q: ?Sized,
+        T: Borrow<Q>,
+    {
+        self.take(value).is_some()
+    }
+
+    /// Retains only the elements specified by the predicate.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.map.retain(|k, _| f(k));
+    }
+
+    /// Returns an iterator over the elements found in exactly one of `self`
+    /// and `other`, in ascending order if the sets are sorted and otherwise
+    /// yielding those of `self` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySet;
+    ///
+    /// let a: ArraySet<u8, 4> = [1, 2, 3].iter().copied().collect();
+    /// let b: ArraySet<u8, 4> = [2, 3, 4].iter().copied().collect();
+    /// assert!(a.symmetric_difference(&b).eq(&[1, 4]));
+    /// ```
+    #[inline]
+    pub fn symmetric_difference<'a, const M: usize>(
+        &'a self,
+        other: &'a ArraySet<T, M, O>,
+    ) -> SymmetricDifference<'a, T, N, M, O>
+    where
+        O: Search<T>,
+    {
+        SymmetricDifference {
+            a: self.iter().peekable(),
+            b: other.iter().peekable(),
+            this: self,
+            other,
+        }
+    }
+
+    /// Removes and returns the element equal to `value`.
+    #[inline]
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        O: Search<Q>,
+        Q: ?Sized,
+        T: Borrow<Q>,
+    {
+        self.map.remove_entry(value).map(|(k, ())| k)
+    }
+
+    /// Returns an iterator over the elements found in `self` or `other`, in
+    /// ascending order if the sets are sorted and otherwise yielding those of
+    /// `self` first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArraySet, SortedArraySet};
+    ///
+    /// let a: ArraySet<u8, 4> = [1, 2].iter().copied().collect();
+    /// let b: ArraySet<u8, 4> = [2, 3].iter().copied().collect();
+    /// let all: ArraySet<u8, 8> = a.union(&b).copied().collect();
+    /// assert_eq!(all, [1, 2, 3].iter().copied().collect::<ArraySet<u8, 3>>());
+    ///
+    /// let a: SortedArraySet<u8, 4> = [4, 1].iter().copied().collect();
+    /// let b: SortedArraySet<u8, 4> = [3, 2, 1].iter().copied().collect();
+    /// assert!(a.union(&b).eq(&[1, 2, 3, 4]));
+    /// ```
+    #[inline]
+    pub fn union<'a, const M: usize>(&'a self, other: &'a ArraySet<T, M, O>) -> Union<'a, T, N, O>
+    where
+        O: Search<T>,
+    {
+        Union {
+            a: self.iter().peekable(),
+            b: other.iter().peekable(),
+            this: self,
+        }
+    }
+}
+
+impl<T, const N: usize, O> Clone for ArraySet<T, N, O>
+where
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize, O> Default for ArraySet<T, N, O>
+where
+    O: Order,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, O> Eq for ArraySet<T, N, O> where O: Search<T> {}
+
+/// Panics if the distinct elements do not all fit. Use
+/// [`ArraySet::try_from_iter`] to handle overflow instead.
+impl<T, const N: usize, O> FromIterator<T> for ArraySet<T, N, O>
+where
+    O: Search<T>,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(set) => set,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+}
+
+impl<T, const N: usize, O> IntoIterator for ArraySet<T, N, O> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize, O> IntoIterator for &'a ArraySet<T, N, O>
+where
+    O: Order,
+{
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Sets are equal if they hold the same elements, whatever their order and
+/// capacity.
+impl<T, const N: usize, const M: usize, O> PartialEq<ArraySet<T, M, O>> for ArraySet<T, N, O>
+where
+    O: Search<T>,
+{
+    #[inline]
+    fn eq(&self, other: &ArraySet<T, M, O>) -> bool {
+        self.len() == other.len() && self.is_subset(other)
+    }
+}
+
+impl<T, const N: usize, O> fmt::Debug for ArraySet<T, N, O>
+where
+    O: Order,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of an [`ArraySet`].
+pub struct Iter<'a, T> {
+    iter: array_map::Keys<'a, T, ()>,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// An iterator that moves out of an [`ArraySet`].
+pub struct IntoIter<T, const N: usize> {
+    iter: VecIntoIter<(T, ()), N>,
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, ())| value)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, ())| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for IntoIter<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter.as_slice().iter().map(|(value, ())| value))
+            .finish()
+    }
+}
+
+/// An iterator over the elements of one [`ArraySet`] that are not in another,
+/// created by [`ArraySet::difference`].
+pub struct Difference<'a, T, const M: usize, O> {
+    iter: Iter<'a, T>,
+    other: &'a ArraySet<T, M, O>,
+}
+
+/// An iterator over the elements of one [`ArraySet`] that are also in
+/// another, created by [`ArraySet::intersection`].
+pub struct Intersection<'a, T, const M: usize, O> {
+    iter: Iter<'a, T>,
+    other: &'a ArraySet<T, M, O>,
+}
+
+macro_rules! filter_iterator {
+    ($name:ident, $keep:literal) => {
+        impl<T, const M: usize, O> Clone for $name<'_, T, M, O> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    iter: self.iter.clone(),
+                    other: self.other,
+                }
+            }
+        }
+
+        impl<T, const M: usize, O> FusedIterator for $name<'_, T, M, O> where O: Search<T> {}
+
+        impl<'a, T, const M: usize, O> Iterator for $name<'a, T, M, O>
+        where
+            O: Search<T>,
+        {
+            type Item = &'a T;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let other = self.other;
+                self.iter.find(|value| other.contains(*value) == $keep)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, self.iter.size_hint().1)
+            }
+        }
+
+        impl<T, const M: usize, O> fmt::Debug for $name<'_, T, M, O>
+        where
+            T: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.iter).finish()
+            }
+        }
+    };
+}
+
+filter_iterator!(Difference, false);
+filter_iterator!(Intersection, true);
+
+/// An iterator over the elements found in exactly one of two [`ArraySet`]s,
+/// created by [`ArraySet::symmetric_difference`].
+pub struct SymmetricDifference<'a, T, const N: usize, const M: usize, O> {
+    a: Peekable<Iter<'a, T>>,
+    b: Peekable<Iter<'a, T>>,
+    this: &'a ArraySet<T, N, O>,
+    other: &'a ArraySet<T, M, O>,
+}
+
+impl<T, const N: usize, const M: usize, O> Clone for SymmetricDifference<'_, T, N, M, O> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            this: self.this,
+            other: self.other,
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize, O> FusedIterator for SymmetricDifference<'_, T, N, M, O> where
+    O: Search<T>
+{
+}
+
+impl<'a, T, const N: usize, const M: usize, O> Iterator for SymmetricDifference<'a, T, N, M, O>
+where
+    O: Search<T>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !O::SORTED {
+            let (this, other) = (self.this, self.other);
+            return self
+                .a
+                .find(|value| !other.contains(*value))
+                .or_else(|| self.b.find(|value| !this.contains(*value)));
+        }
+        while let (Some(x), Some(y)) = (self.a.peek().copied(), self.b.peek().copied()) {
+            match compare::<T, O>(x, y) {
+                Ordering::Less => return self.a.next(),
+                Ordering::Greater => return self.b.next(),
+                Ordering::Equal => {
+                    self.a.next();
+                    self.b.next();
+                }
+            }
+        }
+        self.a.next().or_else(|| self.b.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.len() + self.b.len()))
+    }
+}
+
+impl<T, const N: usize, const M: usize, O> fmt::Debug for SymmetricDifference<'_, T, N, M, O>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SymmetricDifference")
+            .field(&self.a)
+            .field(&self.b)
+            .finish()
+    }
+}
+
+/// An iterator over the elements found in either of two [`ArraySet`]s,
+/// created by [`ArraySet::union`].
+pub struct Union<'a, T, const N: usize, O> {
+    a: Peekable<Iter<'a, T>>,
+    b: Peekable<Iter<'a, T>>,
+    this: &'a ArraySet<T, N, O>,
+}
+
+impl<T, const N: usize, O> Clone for Union<'_, T, N, O> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            this: self.this,
+        }
+    }
+}
+
+impl<T, const N: usize, O> FusedIterator for Union<'_, T, N, O> where O: Search<T> {}
+
+impl<'a, T, const N: usize, O> Iterator for Union<'a, T, N, O>
+where
+    O: Search<T>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !O::SORTED {
+            let this = self.this;
+            return self
+                .a
+                .next()
+                .or_else(|| self.b.find(|value| !this.contains(*value)));
+        }
+        if let (Some(x), Some(y)) = (self.a.peek().copied(), self.b.peek().copied()) {
+            match compare::<T, O>(x, y) {
+                Ordering::Less => return self.a.next(),
+                Ordering::Greater => return self.b.next(),
+                Ordering::Equal => {
+                    self.b.next();
+                    return self.a.next();
+                }
+            }
+        }
+        self.a.next().or_else(|| self.b.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.len(), self.b.len());
+        (a, Some(a + b))
+    }
+}
+
+impl<T, const N: usize, O> fmt::Debug for Union<'_, T, N, O>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Union")
+            .field(&self.a)
+            .field(&self.b)
+            .finish()
+    }
+}
+
+/// Compares two elements of sets that are [`Sorted`] by `O`.
+///
+/// `Search` is the only way to compare elements without an `Ord` bound, so
+/// this looks up `a` in a one-element slice holding `b`.
+fn compare<T, O>(a: &T, b: &T) -> Ordering
+where
+    O: Search<T>,
+{
+    match O::search(slice::from_ref(&(b, ())), a) {
+        Ok(_) => Ordering::Equal,
+        Err(0) => Ordering::Less,
+        Err(_) => Ordering::Greater,
+    }
+}
@@ -0,0 +1,431 @@
+//! A fixed-capacity slab that keeps the keys of its values stable.
+
+use core::{
+    fmt,
+    iter::{Enumerate, FusedIterator},
+    mem,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{ArrayVec, IntoIter as VecIntoIter};
+
+/// Identifies a value in an [`ArraySlab`].
+///
+/// A key stays valid until its value is removed. It may then be handed out
+/// again by a later insertion.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Key(usize);
+
+impl Key {
+    /// Constructs a key from the index of a slot.
+    #[inline]
+    pub const fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    /// Returns the index of the slot the key refers to, which is less than
+    /// the capacity of the slab.
+    #[inline]
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone)]
+enum Slot<T> {
+    Occupied(T),
+    /// Links to the next vacant slot, or to `entries.len()` for the last one
+    Vacant(usize),
+}
+
+/// A fixed-capacity pool of values addressed by [`Key`]s, stored inline.
+///
+/// Unlike indices into an [`ArrayVec`], the key of a value never changes
+/// while the value is in the slab. Vacant slots form a free list threaded
+/// through the storage, so inserting, removing and looking up values all
+/// take constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArraySlab;
+///
+/// let mut connections: ArraySlab<&str, 2> = ArraySlab::new();
+/// let a = connections.insert("10.0.0.1").unwrap();
+/// let b = connections.insert("10.0.0.2").unwrap();
+/// assert_eq!(connections.insert("10.0.0.3"), Err("10.0.0.3"));
+///
+/// assert_eq!(connections.remove(a), Some("10.0.0.1"));
+/// assert_eq!(connections[b], "10.0.0.2");
+/// let c = connections.insert("10.0.0.3").unwrap();
+/// assert_eq!(c, a);
+/// ```
+#[derive(Clone)]
+pub struct ArraySlab<T, const N: usize> {
+    entries: ArrayVec<Slot<T>, N>,
+    len: usize,
+    next_free: usize,
+}
+
+impl<T, const N: usize> ArraySlab<T, N> {
+    // Constructors
+
+    /// Constructs a new, empty slab.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: ArrayVec::new(),
+            len: 0,
+            next_free: 0,
+        }
+    }
+
+    // Methods
+
+    /// Returns the number of values the slab can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all values, invalidating every key.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.len = 0;
+        self.next_free = 0;
+    }
+
+    /// Returns `true` if `key` refers to a value.
+    #[inline]
+    pub fn contains(&self, key: Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value of `key`.
+    #[inline]
+    pub fn get(&self, key: Key) -> Option<&T> {
+        match self.entries.get(key.0) {
+            Some(Slot::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value of `key`.
+    #[inline]
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.entries.get_mut(key.0) {
+            Some(Slot::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Inserts a value, returning its key, or returns the value back if the
+    /// slab is full.
+    pub fn insert(&mut self, value: T) -> Result<Key, T> {
+        let key = self.next_free;
+        match self.entries.get_mut(key) {
+            Some(slot) => match mem::replace(slot, Slot::Occupied(value)) {
+                Slot::Vacant(next) => self.next_free = next,
+                Slot::Occupied(_) => unreachable!(),
+            },
+            None => {
+                if let Err(err) = self.entries.try_push(Slot::Occupied(value)) {
+                    return match err.element() {
+                        Slot::Occupied(value) => Err(value),
+                        Slot::Vacant(_) => unreachable!(),
+                    };
+                }
+                self.next_free = self.entries.len();
+            }
+        }
+        self.len += 1;
+        Ok(Key(key))
+    }
+
+    /// Returns `true` if the slab holds no values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the slab is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns an iterator over the keys and values, ordered by key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            inner: self.entries.iter().enumerate(),
+            len: self.len,
+        }
+    }
+
+    /// Returns an iterator over the keys and mutable values, ordered by key.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            inner: self.entries.iter_mut().enumerate(),
+            len: self.len,
+        }
+    }
+
+    /// Returns the number of values.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Removes and returns the value of `key`, or returns [`None`] if `key`
+    /// does not refer to a value.
+    ///
+    /// The key may then be handed out again by [`ArraySlab::insert`].
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        let slot = self.entries.get_mut(key.0)?;
+        if let Slot::Vacant(_) = slot {
+            return None;
+        }
+        match mem::replace(slot, Slot::Vacant(self.next_free)) {
+            Slot::Occupied(value) => {
+                self.next_free = key.0;
+                self.len -= 1;
+                Some(value)
+            }
+            Slot::Vacant(_) => unreachable!(),
+        }
+    }
+
+    /// Retains only the values specified by the predicate. The keys of the
+    /// retained values do not change.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Key, &mut T) -> bool,
+    {
+        for idx in 0..self.entries.len() {
+            let keep = match &mut self.entries[idx] {
+                Slot::Occupied(value) => f(Key(idx), value),
+                Slot::Vacant(_) => true,
+            };
+            if !keep {
+                let _ = self.remove(Key(idx));
+            }
+        }
+    }
+
+    /// Returns the key that the next call to [`ArraySlab::insert`] will
+    /// return, or [`None`] if the slab is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArraySlab;
+    ///
+    /// let mut nodes: ArraySlab<(usize, u32), 4> = ArraySlab::new();
+    /// let key = nodes.vacant_key().unwrap();
+    /// // A node that refers to itself
+    /// assert_eq!(nodes.insert((key.index(), 7)), Ok(key));
+    /// ```
+    #[inline]
+    pub fn vacant_key(&self) -> Option<Key> {
+        if self.is_full() {
+            None
+        } else {
+            Some(Key(self.next_free))
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArraySlab<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Index<Key> for ArraySlab<T, N> {
+    type Output = T;
+
+    /// Panics if `key` does not refer to a value.
+    #[inline]
+    fn index(&self, key: Key) -> &T {
+        match self.get(key) {
+            Some(value) => value,
+            None => panic!("invalid key"),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<Key> for ArraySlab<T, N> {
+    /// Panics if `key` does not refer to a value.
+    #[inline]
+    fn index_mut(&mut self, key: Key) -> &mut T {
+        match self.get_mut(key) {
+            Some(value) => value,
+            None => panic!("invalid key"),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArraySlab<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = (Key, T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            inner: self.entries.into_iter().enumerate(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArraySlab<T, N> {
+    type IntoIter = Iter<'a, T>;
+    type Item = (Key, &'a T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArraySlab<T, N> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = (Key, &'a mut T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ArraySlab<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+macro_rules! iterator {
+    (
+        $(#[$attr:meta])*
+        $name:ident<$($lt:lifetime,)? T $(, const $n:ident: usize)?>,
+        $inner:ty,
+        $value:ty,
+        $pattern:pat => $slot_value:expr
+    ) => {
+        $(#[$attr])*
+        pub struct $name<$($lt,)? T $(, const $n: usize)?> {
+            inner: Enumerate<$inner>,
+            len: usize,
+        }
+
+        impl<$($lt,)? T $(, const $n: usize)?> DoubleEndedIterator for $name<$($lt,)? T $(, $n)?> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                while let Some((idx, slot)) = self.inner.next_back() {
+                    if let $pattern = slot {
+                        self.len -= 1;
+                        return Some((Key(idx), $slot_value));
+                    }
+                }
+                None
+            }
+        }
+
+        impl<$($lt,)? T $(, const $n: usize)?> ExactSizeIterator for $name<$($lt,)? T $(, $n)?> {}
+
+        impl<$($lt,)? T $(, const $n: usize)?> FusedIterator for $name<$($lt,)? T $(, $n)?> {}
+
+        impl<$($lt,)? T $(, const $n: usize)?> Iterator for $name<$($lt,)? T $(, $n)?> {
+            type Item = (Key, $value);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                while let Some((idx, slot)) = self.inner.next() {
+                    if let $pattern = slot {
+                        self.len -= 1;
+                        return Some((Key(idx), $slot_value));
+                    }
+                }
+                None
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+    };
+}
+
+iterator!(
+    /// An iterator over the keys and values of an [`ArraySlab`].
+    Iter<'a, T>,
+    slice::Iter<'a, Slot<T>>,
+    &'a T,
+    Slot::Occupied(value) => value
+);
+
+iterator!(
+    /// An iterator over the keys and mutable values of an [`ArraySlab`].
+    IterMut<'a, T>,
+    slice::IterMut<'a, Slot<T>>,
+    &'a mut T,
+    Slot::Occupied(value) => value
+);
+
+iterator!(
+    /// An iterator that moves the keys and values out of an [`ArraySlab`].
+    IntoIter<T, const N: usize>,
+    VecIntoIter<Slot<T>, N>,
+    T,
+    Slot::Occupied(value) => value
+);
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Iter<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<T> fmt::Debug for IterMut<'_, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlabIterMut")
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for IntoIter<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlabIntoIter")
+            .field("len", &self.len)
+            .finish()
+    }
+}
@@ -0,0 +1,825 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{Deref, DerefMut, RangeBounds},
+    ptr::{self, NonNull},
+    str::{self, Chars, FromStr, Utf8Error},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{range_to_indices, ArrayVec, CapacityError};
+
+/// A fixed-capacity, UTF-8 encoded string stored inline.
+///
+/// It wraps an `ArrayVec<u8, N>`, so `N` is the capacity in bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayString;
+///
+/// let mut s: ArrayString<16> = ArrayString::new();
+/// s.push_str("hello");
+/// s.push(' ');
+/// s.push_str("world");
+/// assert_eq!(s, "hello world");
+/// ```
+#[derive(Clone, Default)]
+pub struct ArrayString<const N: usize> {
+    vec: ArrayVec<u8, N>,
+}
+
+impl<const N: usize> ArrayString<N> {
+    // Constructors
+
+    /// Constructs a new, empty `ArrayString`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// const EMPTY: ArrayString<10> = ArrayString::new();
+    ///
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vec: ArrayVec::new(),
+        }
+    }
+
+    /// Converts a vector of bytes to an `ArrayString`, failing if the bytes
+    /// are not valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayString, ArrayVec};
+    ///
+    /// let s = ArrayString::from_utf8(ArrayVec::from_array(*b"hi")).unwrap();
+    /// assert_eq!(s, "hi");
+    ///
+    /// let err = ArrayString::from_utf8(ArrayVec::from_array([0xff])).unwrap_err();
+    /// assert_eq!(err.into_bytes(), [0xff]);
+    /// ```
+    pub fn from_utf8(vec: ArrayVec<u8, N>) -> Result<Self, FromUtf8Error<N>> {
+        match str::from_utf8(&vec) {
+            Ok(_) => Ok(Self { vec }),
+            Err(error) => Err(FromUtf8Error { bytes: vec, error }),
+        }
+    }
+
+    /// Converts a vector of bytes to an `ArrayString` without checking that
+    /// they are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes *must* be valid UTF-8.
+    #[inline]
+    pub const unsafe fn from_utf8_unchecked(vec: ArrayVec<u8, N>) -> Self {
+        Self { vec }
+    }
+
+    /// Constructs an `ArrayString` holding a copy of `s`.
+    ///
+    /// Panics if `s` is longer than `N` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// const S: ArrayString<4> = ArrayString::copy_from_str("abc");
+    /// assert_eq!(S, "abc");
+    /// ```
+    pub const fn copy_from_str(s: &str) -> Self {
+        if s.len() > N {
+            panic!("capacity overflow");
+        }
+
+        let mut vec = ArrayVec::new();
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), vec.as_mut_ptr(), s.len());
+            vec.set_len(s.len());
+        }
+        Self { vec }
+    }
+
+    /// Constructs an `ArrayString` holding a copy of `s`, or returns `s` in a
+    /// [`CapacityError`] if it is longer than `N` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let s = ArrayString::<4>::try_from_str("abc").unwrap();
+    /// assert_eq!(s, "abc");
+    ///
+    /// assert!(ArrayString::<2>::try_from_str("abc").is_err());
+    /// ```
+    pub const fn try_from_str(s: &str) -> Result<Self, CapacityError<&str>> {
+        if s.len() > N {
+            return Err(CapacityError::new(s));
+        }
+        Ok(Self::copy_from_str(s))
+    }
+
+    /// Returns the capacity of the string in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let s: ArrayString<8> = ArrayString::new();
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the length of the string in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let s = ArrayString::<8>::try_from_str("ß").unwrap();
+    /// assert_eq!(s.len(), 2);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if the string is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Appends a character to the end of the string.
+    ///
+    /// Panics if the string is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s: ArrayString<4> = ArrayString::new();
+    /// s.push('a');
+    /// s.push('é');
+    /// assert_eq!(s, "aé");
+    /// ```
+    #[inline]
+    pub fn push(&mut self, ch: char) {
+        if self.try_push(ch).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Appends a character to the end of the string, or returns it in a
+    /// [`CapacityError`] if it does not fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s: ArrayString<2> = ArrayString::new();
+    /// assert!(s.try_push('a').is_ok());
+    /// assert_eq!(s.try_push('é').unwrap_err().element(), 'é');
+    /// assert_eq!(s, "a");
+    /// ```
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        let mut buf = [0; 4];
+        match self.try_push_str(ch.encode_utf8(&mut buf)) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(CapacityError::new(ch)),
+        }
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// Panics if it does not fit, without appending any of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s: ArrayString<8> = ArrayString::new();
+    /// s.push_str("foo");
+    /// s.push_str("bar");
+    /// assert_eq!(s, "foobar");
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        if self.try_push_str(string).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Appends a string slice to the end of the string, or returns it in a
+    /// [`CapacityError`] if it does not fit.
+    ///
+    /// The string is left unchanged on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s: ArrayString<4> = ArrayString::new();
+    /// assert!(s.try_push_str("foo").is_ok());
+    /// assert_eq!(s.try_push_str("bar").unwrap_err().element(), "bar");
+    /// assert_eq!(s, "foo");
+    /// ```
+    pub fn try_push_str<'a>(&mut self, string: &'a str) -> Result<(), CapacityError<&'a str>> {
+        if string.len() > N - self.len() {
+            return Err(CapacityError::new(string));
+        }
+
+        match self.vec.extend_from_copyable_slice(string.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Removes the last character from the string and returns it, or [`None`]
+    /// if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<8>::try_from_str("aé").unwrap();
+    /// assert_eq!(s.pop(), Some('é'));
+    /// assert_eq!(s.pop(), Some('a'));
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        unsafe { self.vec.set_len(new_len) };
+        Some(ch)
+    }
+
+    /// Shortens the string to `new_len` bytes. Has no effect if `new_len` is
+    /// greater than the current length.
+    ///
+    /// Panics if `new_len` does not lie on a `char` boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<8>::try_from_str("héllo").unwrap();
+    /// s.truncate(3);
+    /// assert_eq!(s, "hé");
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            assert!(
+                self.is_char_boundary(new_len),
+                "new_len is not a char boundary"
+            );
+            self.vec.truncate(new_len);
+        }
+    }
+
+    /// Truncates the string to zero length.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// Inserts a character at byte position `idx`.
+    ///
+    /// Panics if `idx` is out of bounds or not on a `char` boundary, or if the
+    /// character does not fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<8>::try_from_str("ac").unwrap();
+    /// s.insert(1, 'b');
+    /// assert_eq!(s, "abc");
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        if self.try_insert(idx, ch).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Inserts a character at byte position `idx`, or returns it in a
+    /// [`CapacityError`] if it does not fit.
+    ///
+    /// Panics if `idx` is out of bounds or not on a `char` boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<3>::try_from_str("ac").unwrap();
+    /// assert!(s.try_insert(1, 'b').is_ok());
+    /// assert!(s.try_insert(0, 'z').is_err());
+    /// assert_eq!(s, "abc");
+    /// ```
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), CapacityError<char>> {
+        assert!(
+            self.is_char_boundary(idx),
+            "insertion index is not a char boundary"
+        );
+
+        let mut buf = [0; 4];
+        let bytes = ch.encode_utf8(&mut buf).as_bytes();
+        if bytes.len() > N - self.len() {
+            return Err(CapacityError::new(ch));
+        }
+
+        unsafe { self.insert_bytes(idx, bytes) };
+        Ok(())
+    }
+
+    /// Removes the character at byte position `idx` and returns it.
+    ///
+    /// Panics if `idx` is not on a `char` boundary, or if it is not smaller
+    /// than the length of the string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<8>::try_from_str("aéb").unwrap();
+    /// assert_eq!(s.remove(1), 'é');
+    /// assert_eq!(s, "ab");
+    /// ```
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+
+        let next = idx + ch.len_utf8();
+        let len = self.len();
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            ptr::copy(ptr.add(next), ptr.add(idx), len - next);
+            self.vec.set_len(len - (next - idx));
+        }
+        ch
+    }
+
+    /// Retains only the characters for which `f` returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<16>::try_from_str("f_o_ob_ar").unwrap();
+    /// s.retain(|c| c != '_');
+    /// assert_eq!(s, "foobar");
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        /// Commits the characters kept so far, also if `f` panics.
+        struct SetLenOnDrop<'a, const N: usize> {
+            s: &'a mut ArrayString<N>,
+            idx: usize,
+            del_bytes: usize,
+        }
+
+        impl<const N: usize> Drop for SetLenOnDrop<'_, N> {
+            fn drop(&mut self) {
+                let new_len = self.idx - self.del_bytes;
+                unsafe { self.s.vec.set_len(new_len) };
+            }
+        }
+
+        let len = self.len();
+        let mut guard = SetLenOnDrop {
+            s: self,
+            idx: 0,
+            del_bytes: 0,
+        };
+
+        while guard.idx < len {
+            let ptr = guard.s.vec.as_mut_ptr();
+            // SAFETY: `idx..len` is untouched and starts on a char boundary.
+            let ch = unsafe {
+                let rest = core::slice::from_raw_parts(ptr.add(guard.idx), len - guard.idx);
+                str::from_utf8_unchecked(rest)
+                    .chars()
+                    .next()
+                    .unwrap_unchecked()
+            };
+            let ch_len = ch.len_utf8();
+
+            if !f(ch) {
+                guard.del_bytes += ch_len;
+            } else if guard.del_bytes > 0 {
+                unsafe {
+                    ptr::copy(
+                        ptr.add(guard.idx),
+                        ptr.add(guard.idx - guard.del_bytes),
+                        ch_len,
+                    )
+                };
+            }
+
+            guard.idx += ch_len;
+        }
+    }
+
+    /// Removes the characters in the byte range `range` from the string,
+    /// returning them as an iterator.
+    ///
+    /// The string is only changed once the iterator is dropped.
+    ///
+    /// Panics if the range is out of bounds or does not lie on `char`
+    /// boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<16>::try_from_str("α is alpha").unwrap();
+    /// let beta_offset = s.find(' ').unwrap();
+    /// let removed: String = s.drain(..beta_offset).collect();
+    /// assert_eq!(removed, "α");
+    /// assert_eq!(s, " is alpha");
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> StringDrain<'_, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = match range_to_indices(range, self.len()) {
+            Ok(indices) => indices,
+            Err(_) => panic!("drain range out of bounds"),
+        };
+        assert!(
+            self.is_char_boundary(start),
+            "drain start is not a char boundary"
+        );
+        assert!(
+            self.is_char_boundary(end),
+            "drain end is not a char boundary"
+        );
+
+        let string = NonNull::from(&mut *self);
+        // SAFETY: The `Drain` holds a mutable borrow of the string, and
+        // only changes it on drop, after `iter` is gone.
+        let chars = unsafe { (*(self.as_str() as *const str)).get_unchecked(start..end) }.chars();
+
+        StringDrain {
+            string,
+            start,
+            end,
+            iter: chars,
+        }
+    }
+
+    /// Extracts a string slice containing the entire string.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    /// Extracts a mutable string slice containing the entire string.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) }
+    }
+
+    /// Returns the contents of the string as bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.vec.as_slice()
+    }
+
+    /// Returns a mutable reference to the underlying vector of bytes.
+    ///
+    /// # Safety
+    ///
+    /// The bytes *must* still be valid UTF-8 once the borrow ends.
+    #[inline]
+    pub unsafe fn as_mut_vec(&mut self) -> &mut ArrayVec<u8, N> {
+        &mut self.vec
+    }
+
+    /// Converts the string into its underlying vector of bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let s = ArrayString::<4>::try_from_str("hi").unwrap();
+    /// assert_eq!(s.into_bytes(), *b"hi");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> ArrayVec<u8, N> {
+        self.vec
+    }
+
+    /// Inserts `bytes` at `idx`.
+    ///
+    /// `idx` must be a char boundary and `bytes` must be valid UTF-8 that fits.
+    unsafe fn insert_bytes(&mut self, idx: usize, bytes: &[u8]) {
+        let len = self.len();
+        let amt = bytes.len();
+        let ptr = self.vec.as_mut_ptr();
+
+        ptr::copy(ptr.add(idx), ptr.add(idx + amt), len - idx);
+        ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(idx), amt);
+        self.vec.set_len(len + amt);
+    }
+}
+
+impl<const N: usize> AsMut<str> for ArrayString<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ArrayString<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> Borrow<str> for ArrayString<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+impl<const N: usize> BorrowMut<str> for ArrayString<N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayString<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> FromStr for ArrayString<N> {
+    type Err = CapacityError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s).map_err(CapacityError::simplify)
+    }
+}
+
+/// Hashes the same as the equivalent `str`, so that lookups through
+/// `Borrow<str>` work.
+impl<const N: usize> Hash for ArrayString<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const N: usize> Ord for ArrayString<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+macro_rules! __impl_str_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<$($vars)*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool { self[..] == other[..] }
+        }
+    }
+}
+
+__impl_str_eq! { [const N: usize, const M: usize] ArrayString<N>, ArrayString<M> }
+__impl_str_eq! { [const N: usize] ArrayString<N>, str }
+__impl_str_eq! { [const N: usize] str, ArrayString<N> }
+__impl_str_eq! { ['a, const N: usize] ArrayString<N>, &'a str }
+__impl_str_eq! { ['a, const N: usize] &'a str, ArrayString<N> }
+#[cfg(feature = "alloc")]
+__impl_str_eq! { [const N: usize] ArrayString<N>, String }
+#[cfg(feature = "alloc")]
+__impl_str_eq! { [const N: usize] String, ArrayString<N> }
+
+impl<const N: usize> PartialOrd for ArrayString<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Fails with [`fmt::Error`] when the formatted output does not fit. Each
+/// string slice is written whole or not at all.
+///
+/// # Example
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use stack_based_vec::ArrayString;
+///
+/// let mut s: ArrayString<8> = ArrayString::new();
+/// write!(s, "{}-{}", 12, 34).unwrap();
+/// assert_eq!(s, "12-34");
+/// assert!(write!(s, "{}", 5678).is_err());
+/// assert_eq!(s, "12-34");
+/// ```
+impl<const N: usize> fmt::Write for ArrayString<N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+/// The error returned by [`ArrayString::from_utf8`].
+///
+/// It holds the bytes that were passed in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FromUtf8Error<const N: usize> {
+    bytes: ArrayVec<u8, N>,
+    error: Utf8Error,
+}
+
+impl<const N: usize> FromUtf8Error<N> {
+    /// Returns the bytes that were attempted to convert.
+    #[inline]
+    pub fn into_bytes(self) -> ArrayVec<u8, N> {
+        self.bytes
+    }
+
+    /// Returns details about the conversion failure.
+    #[inline]
+    pub fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl<const N: usize> fmt::Display for FromUtf8Error<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> std::error::Error for FromUtf8Error<N> {}
+
+/// A draining iterator over the characters of an [`ArrayString`].
+///
+/// This is created by [`ArrayString::drain`].
+pub struct StringDrain<'a, const N: usize> {
+    /// Will be used as &'a mut ArrayString in the destructor
+    string: NonNull<ArrayString<N>>,
+    /// Start of the part to remove
+    start: usize,
+    /// End of the part to remove
+    end: usize,
+    /// Current remaining range to remove
+    iter: Chars<'a>,
+}
+
+impl<const N: usize> StringDrain<'_, N> {
+    /// Returns the remaining (sub)string of this iterator as a slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayString;
+    ///
+    /// let mut s = ArrayString::<8>::try_from_str("abc").unwrap();
+    /// let mut drain = s.drain(..);
+    /// assert_eq!(drain.as_str(), "abc");
+    /// let _ = drain.next().unwrap();
+    /// assert_eq!(drain.as_str(), "bc");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        self.iter.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for StringDrain<'_, N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for StringDrain<'_, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.iter.next_back()
+    }
+}
+
+impl<const N: usize> Drop for StringDrain<'_, N> {
+    fn drop(&mut self) {
+        unsafe {
+            // Use ArrayVec::drain. "Reaffirm" the bounds checks to avoid
+            // panic code being inserted again.
+            let vec = &mut self.string.as_mut().vec;
+            if self.start <= self.end && self.end <= vec.len() {
+                vec.drain(self.start..self.end);
+            }
+        }
+    }
+}
+
+impl<const N: usize> FusedIterator for StringDrain<'_, N> {}
+
+impl<const N: usize> Iterator for StringDrain<'_, N> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+impl<const N: usize> fmt::Debug for StringDrain<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StringDrain").field(&self.as_str()).finish()
+    }
+}
+
+unsafe impl<const N: usize> Send for StringDrain<'_, N> {}
+unsafe impl<const N: usize> Sync for StringDrain<'_, N> {}
@@ -0,0 +1,395 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut},
+    ptr,
+    slice::{self, Iter, IterMut},
+};
+
+use crate::{ArrayVec, CapacityError};
+
+/// An inline vector of `Copy` elements that is itself `Copy`.
+///
+/// [`ArrayVec`] drops its elements, so it cannot be `Copy` even when `T` is.
+/// `CopyArrayVec` has no destructor and can be copied implicitly. It derefs to
+/// a slice and covers the common vector operations; convert it to and from an
+/// `ArrayVec` with [`From`] to use the full API.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::{ArrayVec, CopyArrayVec};
+///
+/// let v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 2]);
+/// let a = CopyArrayVec::from(v);
+/// let mut b = a;
+/// b.push(3);
+/// assert_eq!(a, [1, 2]);
+/// assert_eq!(ArrayVec::from(b), [1, 2, 3]);
+/// ```
+pub struct CopyArrayVec<T, const N: usize>
+where
+    T: Copy,
+{
+    data: MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    // Constructors
+
+    /// Constructs a new, empty vector.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            data: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    // Methods
+
+    /// Extracts a mutable slice of the entire vector.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+
+    /// Extracts a slice of the entire vector.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    /// Returns the number of elements the vector can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Copies and appends the elements of `other`.
+    ///
+    /// Panics if they do not all fit, without appending any of them. See
+    /// [`CopyArrayVec::try_extend_from_slice`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::CopyArrayVec;
+    ///
+    /// let mut v: CopyArrayVec<i32, 4> = CopyArrayVec::new();
+    /// v.extend_from_slice(&[1, 2]);
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        if self.try_extend_from_slice(other).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Returns `true` if the vector has no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the vector is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns an iterator over the elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator that allows modifying each element.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Pops an element from the back of the vector and returns it, or
+    /// [`None`] if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.len -= 1;
+            Some(unsafe { (self.data.as_ptr() as *const T).add(self.len).read() })
+        }
+    }
+
+    /// Appends an element on the back of the vector.
+    ///
+    /// Panics if the vector is full. See [`CopyArrayVec::try_push`].
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        if self.try_push(element).is_err() {
+            panic!("capacity overflow");
+        }
+    }
+
+    /// Shortens the vector to `len` elements. Does nothing if it is not
+    /// longer than that.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+
+    /// Copies and appends the elements of `other`, or returns `other` in a
+    /// [`CapacityError`], leaving the vector unchanged, if they do not all
+    /// fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::CopyArrayVec;
+    ///
+    /// let mut v: CopyArrayVec<i32, 2> = CopyArrayVec::new();
+    /// assert!(v.try_extend_from_slice(&[1, 2, 3]).is_err());
+    /// assert!(v.is_empty());
+    /// ```
+    #[inline]
+    pub fn try_extend_from_slice<'a>(
+        &mut self,
+        other: &'a [T],
+    ) -> Result<(), CapacityError<&'a [T]>> {
+        if other.len() > N - self.len {
+            return Err(CapacityError::new(other));
+        }
+        unsafe {
+            ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                (self.data.as_mut_ptr() as *mut T).add(self.len),
+                other.len(),
+            )
+        };
+        self.len += other.len();
+        Ok(())
+    }
+
+    /// Appends an element on the back of the vector, or returns it in a
+    /// [`CapacityError`] if the vector is full.
+    #[inline]
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        if self.len == N {
+            return Err(CapacityError::new(element));
+        }
+        unsafe {
+            (self.data.as_mut_ptr() as *mut T)
+                .add(self.len)
+                .write(element)
+        };
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Clone for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for CopyArrayVec<T, N> where T: Copy {}
+
+impl<T, const N: usize> Default for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Eq for CopyArrayVec<T, N> where T: Copy + Eq {}
+
+impl<T, const N: usize> From<ArrayVec<T, N>> for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from(vec: ArrayVec<T, N>) -> Self {
+        // `T: Copy` has no destructor, so taking the storage as is leaks
+        // nothing.
+        Self {
+            data: vec.data,
+            len: vec.len,
+        }
+    }
+}
+
+impl<T, const N: usize> From<&ArrayVec<T, N>> for CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from(vec: &ArrayVec<T, N>) -> Self {
+        Self {
+            data: vec.data,
+            len: vec.len,
+        }
+    }
+}
+
+impl<T, const N: usize> From<CopyArrayVec<T, N>> for ArrayVec<T, N>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from(vec: CopyArrayVec<T, N>) -> Self {
+        Self {
+            data: vec.data,
+            len: vec.len,
+        }
+    }
+}
+
+impl<T, const N: usize> Hash for CopyArrayVec<T, N>
+where
+    T: Copy + Hash,
+{
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        Hash::hash(self.as_slice(), state)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut CopyArrayVec<T, N>
+where
+    T: Copy,
+{
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<CopyArrayVec<U, M>> for CopyArrayVec<T, N>
+where
+    T: Copy + PartialEq<U>,
+    U: Copy,
+{
+    #[inline]
+    fn eq(&self, other: &CopyArrayVec<U, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for CopyArrayVec<T, N>
+where
+    T: Copy + PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for CopyArrayVec<T, N>
+where
+    T: Copy + PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for CopyArrayVec<T, N>
+where
+    T: Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
@@ -1,11 +1,14 @@
 use core::{
     fmt,
-    iter::{FusedIterator, TrustedLen},
+    iter::FusedIterator,
     mem,
     ptr::{self, NonNull},
     slice,
 };
 
+#[cfg(feature = "nightly")]
+use core::iter::TrustedLen;
+
 use crate::ArrayVec;
 
 pub struct Drain<'a, T, const N: usize> {
@@ -92,6 +95,7 @@ impl<T, const N: usize> Drop for Drain<'_, T, N> {
 }
 
 impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {
+    #[cfg(feature = "nightly")]
     fn is_empty(&self) -> bool {
         self.iter.is_empty()
     }
@@ -125,4 +129,5 @@ where
 
 unsafe impl<T, const N: usize> Send for Drain<'_, T, N> where T: Send {}
 unsafe impl<T, const N: usize> Sync for Drain<'_, T, N> where T: Sync {}
+#[cfg(feature = "nightly")]
 unsafe impl<T, const N: usize> TrustedLen for Drain<'_, T, N> {}
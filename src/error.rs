@@ -0,0 +1,95 @@
+use core::{
+    fmt,
+    iter::{Chain, Once},
+};
+
+/// The error returned when an `ArrayVec` does not have room for more
+/// elements.
+///
+/// It holds whatever was rejected, so that it is not lost.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayVec;
+///
+/// let mut v = ArrayVec::from_array([1, 2]);
+/// let err = v.try_push(3).unwrap_err();
+/// assert_eq!(err.element(), 3);
+/// ```
+#[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CapacityError<T = ()> {
+    element: T,
+}
+
+impl<T> CapacityError<T> {
+    /// Constructs a new `CapacityError` holding `element`.
+    #[inline]
+    pub const fn new(element: T) -> Self {
+        Self { element }
+    }
+
+    /// Returns the rejected value.
+    #[inline]
+    pub fn element(self) -> T {
+        self.element
+    }
+
+    /// Discards the rejected value, keeping only the error.
+    #[inline]
+    pub fn simplify(self) -> CapacityError {
+        CapacityError { element: () }
+    }
+}
+
+impl<T> fmt::Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CapacityError: {}", self)
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
+
+/// An iterator over the elements that did not fit, held by the
+/// [`CapacityError`] of fallible operations that take an iterator.
+///
+/// The first element is the one taken from the source iterator to detect
+/// the overflow; the rest of the source follows it.
+pub type Remaining<T, I> = Chain<Once<T>, I>;
+
+/// The error returned when an index or range lies outside of an `ArrayVec`.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayVec;
+///
+/// let mut v = ArrayVec::from_array([1, 2]);
+/// assert!(v.try_remove(2).is_err());
+/// assert!(v.try_drain(1..3).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfBoundsError(());
+
+impl OutOfBoundsError {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("index out of bounds")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBoundsError {}
@@ -0,0 +1,107 @@
+use core::{fmt, iter::FusedIterator, ptr, slice};
+
+use crate::ArrayVec;
+
+/// An iterator that removes the elements of a range that match a predicate,
+/// created by [`ArrayVec::extract_if`].
+///
+/// Kept elements are moved back over the removed ones as the iterator
+/// advances, so each element is inspected and moved at most once. If the
+/// iterator is dropped before it is exhausted, the elements that have not
+/// been inspected are kept.
+pub struct ExtractIf<'a, T, F, const N: usize>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut ArrayVec<T, N>,
+    /// Index of the next element to inspect
+    idx: usize,
+    /// Index after the last element to inspect
+    end: usize,
+    /// Number of elements removed so far
+    del: usize,
+    /// Length of the vector before extraction started
+    old_len: usize,
+    pred: F,
+}
+
+impl<'a, T, F, const N: usize> ExtractIf<'a, T, F, N>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(vec: &'a mut ArrayVec<T, N>, start: usize, end: usize, pred: F) -> Self {
+        let old_len = vec.len;
+        // Holes are created as elements are removed, so the length is only
+        // restored once the iterator is dropped. Leaking it leaks the
+        // elements instead of exposing the holes.
+        vec.len = 0;
+        Self {
+            vec,
+            idx: start,
+            end,
+            del: 0,
+            old_len,
+            pred,
+        }
+    }
+}
+
+impl<T, F, const N: usize> Drop for ExtractIf<'_, T, F, N>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        // Also runs if the predicate panics, in which case `idx` still points
+        // at the element that was being inspected and it is kept.
+        if self.del > 0 && self.idx < self.old_len {
+            unsafe {
+                let src = self.vec.as_mut_ptr().add(self.idx);
+                ptr::copy(src, src.sub(self.del), self.old_len - self.idx);
+            }
+        }
+        self.vec.len = self.old_len - self.del;
+    }
+}
+
+impl<T, F, const N: usize> FusedIterator for ExtractIf<'_, T, F, N> where F: FnMut(&mut T) -> bool {}
+
+impl<T, F, const N: usize> Iterator for ExtractIf<'_, T, F, N>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let base = self.vec.as_mut_ptr();
+        while self.idx < self.end {
+            let i = self.idx;
+            // SAFETY: Elements in `..i - del` and `i..old_len` are initialized.
+            let cur = unsafe { &mut *base.add(i) };
+            let extract = (self.pred)(cur);
+            self.idx += 1;
+            if extract {
+                self.del += 1;
+                return Some(unsafe { ptr::read(cur) });
+            } else if self.del > 0 {
+                unsafe { ptr::copy_nonoverlapping(cur, base.add(i - self.del), 1) };
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<T, F, const N: usize> fmt::Debug for ExtractIf<'_, T, F, N>
+where
+    F: FnMut(&mut T) -> bool,
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining =
+            unsafe { slice::from_raw_parts(self.vec.as_ptr().add(self.idx), self.end - self.idx) };
+        f.debug_tuple("ExtractIf").field(&remaining).finish()
+    }
+}
@@ -0,0 +1,144 @@
+use core::{fmt, hint::unreachable_unchecked, iter::FusedIterator, ptr, slice};
+
+#[cfg(feature = "nightly")]
+use core::iter::TrustedLen;
+
+use crate::ArrayVec;
+
+/// An iterator that moves out of an `ArrayVec`.
+///
+/// This is created by the `into_iter` method on `ArrayVec` (provided by the
+/// [`IntoIterator`] trait).
+pub struct IntoIter<T, const N: usize> {
+    /// Backing storage. Its `len` is kept at zero so that only
+    /// `start..end` is dropped, by `IntoIter` itself.
+    pub(crate) vec: ArrayVec<T, N>,
+    /// Index of the next element to yield from the front
+    pub(crate) start: usize,
+    /// One past the index of the next element to yield from the back
+    pub(crate) end: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Returns the remaining items of this iterator as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::from_array(['a', 'b', 'c']);
+    /// let mut into_iter = v.into_iter();
+    /// assert_eq!(into_iter.as_slice(), &['a', 'b', 'c']);
+    /// let _ = into_iter.next().unwrap();
+    /// assert_eq!(into_iter.as_slice(), &['b', 'c']);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.vec.as_ptr().add(self.start), self.end - self.start) }
+    }
+
+    /// Returns the remaining items of this iterator as a mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::from_array(['a', 'b', 'c']);
+    /// let mut into_iter = v.into_iter();
+    /// assert_eq!(into_iter.as_slice(), &['a', 'b', 'c']);
+    /// into_iter.as_mut_slice()[2] = 'z';
+    /// assert_eq!(into_iter.next().unwrap(), 'a');
+    /// assert_eq!(into_iter.next().unwrap(), 'b');
+    /// assert_eq!(into_iter.next().unwrap(), 'z');
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.vec.as_mut_ptr().add(self.start), self.end - self.start)
+        }
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for IntoIter<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> Clone for IntoIter<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut vec = ArrayVec::new();
+        match vec.extend_from_cloneable_slice(self.as_slice()) {
+            Ok(()) => {}
+            Err(_) => unsafe { unreachable_unchecked() },
+        }
+        vec.into_iter()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.vec.as_ptr().add(self.end).read() })
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {
+    #[cfg(feature = "nightly")]
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            let elt = unsafe { self.vec.as_ptr().add(self.start).read() };
+            self.start += 1;
+            Some(elt)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for IntoIter<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[cfg(feature = "nightly")]
+unsafe impl<T, const N: usize> TrustedLen for IntoIter<T, N> {}
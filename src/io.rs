@@ -0,0 +1,194 @@
+#[cfg(feature = "nightly")]
+use std::io::BorrowedBuf;
+use std::io::{self, BufRead, Read, Write};
+#[cfg(not(feature = "nightly"))]
+use std::mem::MaybeUninit;
+
+use crate::ArrayVec;
+
+impl<const N: usize> ArrayVec<u8, N> {
+    /// Reads once from `reader` directly into the spare capacity, appending
+    /// the bytes read, and returns how many there were.
+    ///
+    /// Returns `Ok(0)` at the end of the input or if the vector is full.
+    ///
+    /// Only the `nightly` feature makes this a zero-copy read: the reader
+    /// fills the spare capacity through a
+    /// [`BorrowedBuf`](https://doc.rust-lang.org/std/io/struct.BorrowedBuf.html)
+    /// without initializing it first. On stable, `Read::read` needs an
+    /// initialized `&mut [u8]`, so the whole spare capacity is zeroed before
+    /// each read, which costs a memset of up to `N` bytes per call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut input: &[u8] = b"hello";
+    /// let mut v: ArrayVec<u8, 4> = ArrayVec::new();
+    /// assert_eq!(v.read_from(&mut input).unwrap(), 4);
+    /// assert_eq!(v.read_from(&mut input).unwrap(), 0);
+    /// assert_eq!(v, *b"hell");
+    /// ```
+    pub fn read_from<R>(&mut self, reader: &mut R) -> io::Result<usize>
+    where
+        R: Read + ?Sized,
+    {
+        #[cfg(feature = "nightly")]
+        let n = {
+            let mut buf = BorrowedBuf::from(self.spare_capacity_mut());
+            reader.read_buf(buf.unfilled())?;
+            // `BorrowedBuf` only counts the bytes the reader filled.
+            buf.len()
+        };
+
+        #[cfg(not(feature = "nightly"))]
+        let n = {
+            let spare = self.spare_capacity_mut();
+            spare.fill(MaybeUninit::new(0));
+            // SAFETY: Every byte was initialized above.
+            let buf = unsafe { &mut *(spare as *mut [MaybeUninit<u8>] as *mut [u8]) };
+            let n = reader.read(buf)?;
+            if n > buf.len() {
+                panic!("reader returned more bytes than the buffer holds");
+            }
+            n
+        };
+
+        // SAFETY: The first `n` spare bytes were written by the reader.
+        unsafe { self.set_len(self.len() + n) };
+        Ok(n)
+    }
+}
+
+/// Appends as many bytes as fit.
+///
+/// A full vector makes writes short, so [`Write::write_all`] fails with
+/// [`io::ErrorKind::WriteZero`] after appending the bytes that fit.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{ErrorKind, Write};
+/// use stack_based_vec::ArrayVec;
+///
+/// let mut frame: ArrayVec<u8, 8> = ArrayVec::new();
+/// write!(frame, "id={}", 42).unwrap();
+/// assert_eq!(frame, *b"id=42");
+///
+/// let err = frame.write_all(b"-abcd").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::WriteZero);
+/// assert_eq!(frame, *b"id=42-ab");
+/// ```
+impl<const N: usize> Write for ArrayVec<u8, N> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.remaining_capacity());
+        match self.extend_from_copyable_slice(&buf[..len]) {
+            Ok(()) => Ok(len),
+            Err(_) => unreachable!(),
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A reader that consumes the bytes of an `ArrayVec<u8, N>` from the front.
+///
+/// Reading only advances a position; the unread bytes are moved to the front
+/// of the vector once, by [`ArrayVecReader::into_inner`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::{BufRead, Read};
+/// use stack_based_vec::{ArrayVec, ArrayVecReader};
+///
+/// let mut reader = ArrayVecReader::new(ArrayVec::from_array(*b"GET /\nrest"));
+/// let mut line = String::new();
+/// reader.read_line(&mut line).unwrap();
+/// assert_eq!(line, "GET /\n");
+///
+/// let mut byte = [0];
+/// reader.read_exact(&mut byte).unwrap();
+/// assert_eq!(&byte, b"r");
+/// assert_eq!(reader.into_inner(), *b"est");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ArrayVecReader<const N: usize> {
+    vec: ArrayVec<u8, N>,
+    /// Index of the next byte to read
+    pos: usize,
+}
+
+impl<const N: usize> ArrayVecReader<N> {
+    /// Constructs a reader over the bytes of `vec`.
+    #[inline]
+    pub const fn new(vec: ArrayVec<u8, N>) -> Self {
+        Self { vec, pos: 0 }
+    }
+
+    /// Returns the number of bytes read so far.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the bytes that have not been read yet.
+    #[inline]
+    pub fn remaining(&self) -> &[u8] {
+        &self.vec[self.pos..]
+    }
+
+    /// Returns `true` if every byte has been read.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.vec.len()
+    }
+
+    /// Returns the vector, holding only the bytes that have not been read.
+    pub fn into_inner(mut self) -> ArrayVec<u8, N> {
+        self.vec.drain(..self.pos);
+        self.vec
+    }
+}
+
+impl<const N: usize> BufRead for ArrayVecReader<N> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining())
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.vec.len());
+    }
+}
+
+impl<const N: usize> From<ArrayVec<u8, N>> for ArrayVecReader<N> {
+    #[inline]
+    fn from(vec: ArrayVec<u8, N>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<const N: usize> Read for ArrayVecReader<N> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.remaining().read(buf)?;
+        self.pos += n;
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        if let Err(err) = self.remaining().read_exact(buf) {
+            // Like `io::Cursor`, consume everything that was left.
+            self.pos = self.vec.len();
+            return Err(err);
+        }
+        self.pos += buf.len();
+        Ok(())
+    }
+}
@@ -1,49 +1,107 @@
 // #![allow(trivial_casts, missing_docs)]
-#![feature(
-    const_deref,
-    const_fn_trait_bound,
-    const_for,
-    const_intrinsic_copy,
-    const_maybe_uninit_as_ptr,
-    // const_maybe_uninit_assume_init,
-    const_maybe_uninit_write,
-    const_mut_refs,
-    // const_panic,
-    const_precise_live_drops,
-    const_ptr_offset,
-    const_ptr_read,
-    const_ptr_write,
-    const_raw_ptr_deref,
-    const_slice_from_raw_parts,
-    const_trait_impl,
-    const_try,
-    exact_size_is_empty,
-    maybe_uninit_extra,
-    // maybe_uninit_ref,
-    maybe_uninit_uninit_array,
-    option_result_unwrap_unchecked,
-    slice_partition_dedup,
-    trusted_len,
+//! Fixed-capacity vectors and collections stored inline, without a heap.
+//!
+//! # Breaking changes
+//!
+//! [`ArrayVec`] now drops its elements, so it is no longer `Copy`, even when
+//! `T: Copy`. Code that relied on copying it should use [`CopyArrayVec`],
+//! which converts to and from `ArrayVec` with [`From`].
+
+#![no_std]
+#![cfg_attr(
+    feature = "nightly",
+    feature(
+        const_convert,
+        const_default,
+        const_trait_impl,
+        exact_size_is_empty,
+        trusted_len,
+    )
+)]
+#![cfg_attr(
+    all(feature = "nightly", feature = "std"),
+    feature(core_io_borrowed_buf, read_buf)
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array_arena;
+mod array_binary_heap;
+mod array_deque;
+mod array_map;
+mod array_set;
+mod array_slab;
+mod array_string;
+mod copy_array_vec;
 mod drain;
+mod error;
+mod extract_if;
+mod into_iter;
+#[cfg(feature = "std")]
+mod io;
 mod macros;
+mod reservoir;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "alloc")]
+mod small_vec;
+mod sorted_array_vec;
 mod splice;
+mod top_k;
 
 use core::{
     borrow::{Borrow, BorrowMut},
     cmp::Ordering,
+    convert::TryFrom,
     fmt,
+    hash::{Hash, Hasher},
     hint::unreachable_unchecked,
-    iter::IntoIterator,
-    mem::{ManuallyDrop, MaybeUninit},
-    ops::{Bound, Deref, DerefMut, RangeBounds, Index, IndexMut},
+    iter::{self, FromIterator, IntoIterator},
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds},
     ptr::{self, NonNull},
     slice::{self, Iter, IterMut, SliceIndex},
 };
 
+pub use array_arena::{
+    ArrayArena, Drain as ArenaDrain, Handle, Iter as ArenaIter, IterMut as ArenaIterMut,
+};
+pub use array_binary_heap::{ArrayBinaryHeap, Compare, Max, Min, PeekMut};
+pub use array_deque::{
+    ArrayDeque, Drain as DequeDrain, IntoIter as DequeIntoIter, Iter as DequeIter,
+    IterMut as DequeIterMut,
+};
+pub use array_map::{
+    ArrayMap, Entry, Iter as MapIter, IterMut as MapIterMut, Keys as MapKeys, OccupiedEntry, Order,
+    Search, Sorted, SortedArrayMap, Unsorted, VacantEntry, Values as MapValues,
+    ValuesMut as MapValuesMut,
+};
+pub use array_set::{
+    ArraySet, Difference, Intersection, IntoIter as SetIntoIter, Iter as SetIter, SortedArraySet,
+    SymmetricDifference, Union,
+};
+pub use array_slab::{
+    ArraySlab, IntoIter as SlabIntoIter, Iter as SlabIter, IterMut as SlabIterMut, Key,
+};
+pub use array_string::{ArrayString, FromUtf8Error, StringDrain};
+pub use copy_array_vec::CopyArrayVec;
 pub use drain::Drain;
+pub use error::{CapacityError, OutOfBoundsError, Remaining};
+pub use extract_if::ExtractIf;
+pub use into_iter::IntoIter;
+#[cfg(feature = "std")]
+pub use io::ArrayVecReader;
+pub use reservoir::{Reservoir, Rng};
+#[cfg(feature = "alloc")]
+pub use small_vec::{
+    Drain as SmallVecDrain, IntoIter as SmallVecIntoIter, SmallVec, Splice as SmallVecSplice,
+};
+pub use sorted_array_vec::SortedArrayVec;
 pub use splice::Splice;
+pub use top_k::{ByKey, TopK};
 
 // #[doc(hidden)]
 // pub fn __assert_copy<T: Copy>(_: T) {}
@@ -76,6 +134,35 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Constructs an `ArrayVec` of `len` elements, each returned by `f` when
+    /// called with its index.
+    ///
+    /// Panics if `len > N`. See [`ArrayVec::try_from_fn`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v: ArrayVec<usize, 8> = ArrayVec::from_fn(4, |i| i * i);
+    /// assert_eq!(v, [0, 1, 4, 9]);
+    /// ```
+    pub fn from_fn<F>(len: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        if len > N {
+            panic!("capacity overflow");
+        }
+
+        let mut v = Self::new();
+        for i in 0..len {
+            unsafe { v.as_mut_ptr().add(i).write(f(i)) };
+            v.len += 1;
+        }
+        v
+    }
+
     /// Constructs a partially filled `ArrayVec` from an array.
     ///
     /// # Example
@@ -97,31 +184,26 @@ impl<T, const N: usize> ArrayVec<T, N> {
 
         s.len = M;
 
-        let mut array = ManuallyDrop::new(array);
+        let array = ManuallyDrop::new(array);
 
-        // SAFETY: Both pointers are valid
-        unsafe { ptr::copy_nonoverlapping(array.as_mut_ptr(), s.data.as_mut_ptr() as *mut _, M) }
+        // SAFETY: Both pointers are valid, and `ManuallyDrop` is `repr(transparent)`.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                &array as *const ManuallyDrop<[T; M]> as *const T,
+                s.data.as_mut_ptr() as *mut _,
+                M,
+            )
+        }
 
         s
     }
 
+    /// Removes the first `M` elements and returns them as an array, or
+    /// returns [`None`] if there are fewer than `M`.
+    #[deprecated(note = "use `take_front_array`, or `take_back_array` to take from the back")]
+    #[inline]
     pub fn make_filled_array<const M: usize>(&mut self) -> Option<[T; M]> {
-        if self.len >= M {
-            self.len -= M;
-
-            let ptr = self.as_mut_ptr();
-
-            // SAFETY: These bytes get overwritten by the copy below.
-            let v = unsafe { (ptr as *mut [T; M]).read() };
-
-            // SAFETY: We are just copying it back, like in remove(usize).
-            // we have already decreased the len
-            unsafe { ptr::copy::<T>(ptr.add(M), ptr, self.len) };
-
-            Some(v)
-        } else {
-            None
-        }
+        self.take_front_array()
     }
 
     /// Constructs a new, empty `ArrayVec`.
@@ -143,6 +225,60 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Like [`ArrayVec::from_fn`], but fails if `len > N`, without calling `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// assert!(ArrayVec::<usize, 2>::try_from_fn(3, |i| i).is_err());
+    /// ```
+    pub fn try_from_fn<F>(len: usize, f: F) -> Result<Self, CapacityError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        if len > N {
+            Err(CapacityError::new(()))
+        } else {
+            Ok(Self::from_fn(len, f))
+        }
+    }
+
+    /// Constructs an `ArrayVec` from an iterator, failing if it yields more
+    /// than `N` elements.
+    ///
+    /// On overflow, the error holds the filled vector and an iterator over the
+    /// elements that did not fit. Note that one element past the capacity is
+    /// taken from `iter` to detect the overflow; it is the first element of
+    /// the returned iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::<i32, 4>::try_from_iter(1..=3).unwrap();
+    /// assert_eq!(v, [1, 2, 3]);
+    ///
+    /// let (v, rest) = ArrayVec::<i32, 2>::try_from_iter(1..=4).unwrap_err().element();
+    /// assert_eq!(v, [1, 2]);
+    /// assert!(rest.eq(3..=4));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_from_iter<I>(
+        iter: I,
+    ) -> Result<Self, CapacityError<(Self, Remaining<T, I::IntoIter>)>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut v = Self::new();
+        match v.try_extend(iter) {
+            Ok(()) => Ok(v),
+            Err(err) => Err(CapacityError::new((v, err.element()))),
+        }
+    }
+
     /// Returns the length of the inner buffer of the `ArrayVec`.
     ///
     /// Just checking the const parameter is preferred.
@@ -227,9 +363,22 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
-    pub const fn try_push(&mut self, element: T) -> Result<(), T> {
+    /// Appends an element on the back of the vector, or returns it in a
+    /// [`CapacityError`] if the vector is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 1> = ArrayVec::new();
+    ///
+    /// assert!(v.try_push(1).is_ok());
+    /// assert_eq!(v.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub const fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
         if self.len == N {
-            Err(element)
+            Err(CapacityError::new(element))
         } else {
             unsafe { self.as_mut_ptr().add(self.len).write(element) };
 
@@ -262,6 +411,35 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Moves all the elements of `other` to the end of the vector, leaving
+    /// `other` empty.
+    ///
+    /// Panics if they do not all fit, before moving any of them. See
+    /// [`ArrayVec::try_append`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 2]);
+    /// let mut other = ArrayVec::from_array([3, 4]);
+    /// v.append(&mut other);
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub const fn append<const M: usize>(&mut self, other: &mut ArrayVec<T, M>) {
+        if other.len > self.remaining_capacity() {
+            panic!("capacity overflow");
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), self.as_mut_ptr().add(self.len), other.len);
+        }
+        self.len += other.len;
+        other.len = 0;
+    }
+
     /// # Example
     ///
     /// ```rust
@@ -367,15 +545,63 @@ impl<T, const N: usize> ArrayVec<T, N> {
     ///
     /// assert_eq!(v.as_slice(), &[10, 20, 30, 20]);
     /// ```
-    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
     where
         F: FnMut(&mut T, &mut T) -> bool,
     {
-        let len = {
-            let (dedup, _) = self.as_mut_slice().partition_dedup_by(same_bucket);
-            dedup.len()
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+
+        /// Moves the unchecked elements down over the gap left by removed
+        /// duplicates, in case `same_bucket` or a destructor panics.
+        struct FillGapOnDrop<'a, T, const N: usize> {
+            /// Index of the next element to check
+            read: usize,
+            /// Index of the next slot to move a kept element into
+            write: usize,
+            vec: &'a mut ArrayVec<T, N>,
+        }
+
+        impl<T, const N: usize> Drop for FillGapOnDrop<'_, T, N> {
+            fn drop(&mut self) {
+                let len = self.vec.len;
+                let items_left = len - self.read;
+                unsafe {
+                    let ptr = self.vec.as_mut_ptr();
+                    ptr::copy(ptr.add(self.read), ptr.add(self.write), items_left);
+                }
+                self.vec.len = self.write + items_left;
+            }
+        }
+
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            vec: self,
         };
-        self.truncate(len);
+        let ptr = gap.vec.as_mut_ptr();
+
+        while gap.read < len {
+            unsafe {
+                let read_ptr = ptr.add(gap.read);
+                let prev_ptr = ptr.add(gap.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    // Advance first so the guard does not move the dropped element.
+                    gap.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    ptr::copy(read_ptr, ptr.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+        }
+
+        gap.vec.len = gap.write;
+        mem::forget(gap);
     }
 
     /// # Examples
@@ -397,13 +623,18 @@ impl<T, const N: usize> ArrayVec<T, N> {
         self.dedup_by(|a, b| key(a) == key(b))
     }
 
+    /// Removes the elements in `range` from the vector, returning them as an
+    /// iterator.
+    ///
+    /// Panics if the range is out of bounds. See [`ArrayVec::try_drain`].
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     /// let mut v = ArrayVec::from_array([1, 2, 3]);
     /// {
-    ///     let mut iter = v.drain(1..).unwrap();
+    ///     let mut iter = v.drain(1..);
     ///     assert_eq!(iter.next().unwrap(), 2);
     ///     assert_eq!(iter.next().unwrap(), 3);
     /// }
@@ -411,43 +642,21 @@ impl<T, const N: usize> ArrayVec<T, N> {
     /// v.drain(..);
     /// assert_eq!(v.as_slice(), &[]);
     /// ```
-    pub fn drain<R>(&mut self, range: R) -> Option<Drain<'_, T, N>>
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
     where
         R: RangeBounds<usize>,
     {
-        let len = self.len;
-
-        let start = match range.start_bound() {
-            Bound::Included(&n) => n,
-            Bound::Excluded(&n) => n + 1,
-            Bound::Unbounded => 0,
-        };
-
-        let end = match range.end_bound() {
-            Bound::Included(&n) => n + 1,
-            Bound::Excluded(&n) => n,
-            Bound::Unbounded => len,
-        };
-
-        if start > end || end > len {
-            return None;
+        match self.try_drain(range) {
+            Ok(drain) => drain,
+            Err(_) => panic!("drain range out of bounds"),
         }
-
-        // set self.vec length's to start, to be safe in case Drain is leaked
-        self.len = start;
-
-        // Use the borrow in the IterMut to indicate borrowing behavior of the
-        // whole Drain iterator (like &mut T).
-        let range_slice = unsafe { slice::from_raw_parts(self.as_ptr().add(start), end - start) };
-
-        Some(Drain {
-            tail_start: end,
-            tail_len: len - end,
-            iter: range_slice.iter(),
-            vec: NonNull::from(self),
-        })
     }
 
+    /// Clones and appends the elements of `other`.
+    ///
+    /// If they do not all fit, the vector is filled and the error holds the
+    /// part of `other` that was not cloned.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -462,11 +671,14 @@ impl<T, const N: usize> ArrayVec<T, N> {
     /// use stack_based_vec::ArrayVec;
     ///
     /// let mut v: ArrayVec<i32, 2> = ArrayVec::new();
-    /// assert!(v.extend_from_cloneable_slice(&[1, 2, 3]).is_err());
+    /// assert_eq!(v.extend_from_cloneable_slice(&[1, 2, 3]).unwrap_err().element(), &[3]);
     /// assert_eq!(v.as_slice(), &[1, 2]);
     /// ```
     #[inline]
-    pub fn extend_from_cloneable_slice<'a>(&mut self, other: &'a [T]) -> Result<(), &'a [T]>
+    pub fn extend_from_cloneable_slice<'a>(
+        &mut self,
+        other: &'a [T],
+    ) -> Result<(), CapacityError<&'a [T]>>
     where
         T: Clone,
     {
@@ -481,13 +693,18 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
         if other_len > remaining_capacity {
             do_clone!(remaining_capacity);
-            Err(&other[remaining_capacity..])
+            Err(CapacityError::new(&other[remaining_capacity..]))
         } else {
             do_clone!(other_len);
             Ok(())
         }
     }
 
+    /// Copies and appends the elements of `other`.
+    ///
+    /// If they do not all fit, the vector is filled and the error holds the
+    /// part of `other` that was not copied.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -500,11 +717,14 @@ impl<T, const N: usize> ArrayVec<T, N> {
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     /// let mut v: ArrayVec<i32, 2> = ArrayVec::new();
-    /// assert_eq!(v.extend_from_copyable_slice(&[1, 2, 3]).unwrap_err(), &[3]);
+    /// assert_eq!(v.extend_from_copyable_slice(&[1, 2, 3]).unwrap_err().element(), &[3]);
     /// assert_eq!(v.as_slice(), &[1, 2]);
     /// ```
     #[inline]
-    pub const fn extend_from_copyable_slice<'a>(&mut self, other: &'a [T]) -> Result<(), &'a [T]>
+    pub const fn extend_from_copyable_slice<'a>(
+        &mut self,
+        other: &'a [T],
+    ) -> Result<(), CapacityError<&'a [T]>>
     where
         T: Copy,
     {
@@ -518,12 +738,12 @@ impl<T, const N: usize> ArrayVec<T, N> {
             self.len = N;
 
             // We use slice_from_raw_parts so that it is const.
-            Err(unsafe {
+            Err(CapacityError::new(unsafe {
                 &*ptr::slice_from_raw_parts(
                     &other[remaining_capacity],
                     other.len() - remaining_capacity,
                 )
-            })
+            }))
             // Err(&other[remaining_capacity..])
         } else {
             unsafe { ptr::copy_nonoverlapping(other.as_ptr(), dst, other.len()) };
@@ -533,203 +753,1201 @@ impl<T, const N: usize> ArrayVec<T, N> {
         }
     }
 
+    /// Copies the elements in `range` and appends them, or fails without
+    /// copying any of them if they do not all fit.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
     /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
-    /// let mut v: ArrayVec<i32, 2> = ArrayVec::new();
-    /// v.push(2);
     ///
-    /// // Index is out of bounds
-    /// assert!(v.insert(10, 4).is_err());
-    ///
-    /// // Ok
-    /// assert!(v.insert(0, 4).is_ok());
-    /// assert_eq!(v.len(), 2);
-    ///
-    /// // Full capacity
-    /// assert!(v.insert(0, 6).is_err());
+    /// let mut v: ArrayVec<i32, 5> = ArrayVec::from_partial_array([1, 2, 3]);
+    /// assert!(v.extend_from_copyable_within(..).is_err());
+    /// assert!(v.extend_from_copyable_within(1..).is_ok());
+    /// assert_eq!(v, [1, 2, 3, 2, 3]);
     /// ```
-    pub const fn insert(&mut self, idx: usize, element: T) -> Result<(), T> {
-        if idx > self.len || self.len == N {
-            Err(element)
-        } else {
-            let ptr = unsafe { self.as_mut_ptr().add(idx) };
-            unsafe { ptr.copy_to(ptr.add(1), self.len - idx) };
-            unsafe { ptr.write(element) };
-            self.len += 1;
-
-            Ok(())
+    pub fn extend_from_copyable_within<R>(&mut self, range: R) -> Result<(), CapacityError>
+    where
+        R: RangeBounds<usize>,
+        T: Copy,
+    {
+        let (start, end) = match range_to_indices(range, self.len) {
+            Ok(indices) => indices,
+            Err(_) => panic!("range out of bounds"),
+        };
+        let count = end - start;
+        if count > self.remaining_capacity() {
+            return Err(CapacityError::new(()));
         }
+
+        let ptr = self.as_mut_ptr();
+        // SAFETY: The source is initialized and the destination starts at
+        // `len`, so they do not overlap.
+        unsafe { ptr::copy_nonoverlapping(ptr.add(start), ptr.add(self.len), count) };
+        self.len += count;
+        Ok(())
     }
 
-    /// # Examples
+    /// Clones and appends the elements of `other`.
+    ///
+    /// Panics if they do not all fit, without appending any of them. See
+    /// [`ArrayVec::extend_from_cloneable_slice`].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
-    ///
-    /// let mut v = ArrayVec::from_array([1, 2, 3]);
-    /// assert_eq!(v.remove(10), None);
-    /// assert_eq!(v.remove(0), Some(1));
-    /// assert_eq!(v.as_slice(), &[2, 3]);
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::new();
+    /// v.extend_from_slice(&[1, 2]);
+    /// assert_eq!(v.as_slice(), &[1, 2]);
     /// ```
-    pub const fn remove(&mut self, idx: usize) -> Option<T> {
-        if idx >= self.len {
-            None
-        } else {
-            let ptr = unsafe { self.as_mut_ptr().add(idx) };
-            let result = unsafe { ptr.read() };
-            unsafe { ptr.copy_from(ptr.add(1), self.len - idx - 1) };
-            self.len -= 1;
-
-            Some(result)
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        if other.len() > self.remaining_capacity() {
+            panic!("capacity overflow");
+        }
+        match self.extend_from_cloneable_slice(other) {
+            Ok(()) => {}
+            Err(_) => unsafe { unreachable_unchecked() },
         }
     }
 
-    // Can't be const because of drop and trait methods
-
-    /// # Examples
+    /// Clones the elements in `range` and appends them.
+    ///
+    /// Panics if the range is out of bounds or if the elements do not all
+    /// fit, without appending any of them. See
+    /// [`ArrayVec::try_extend_from_within`].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     ///
-    /// let mut v = ArrayVec::from_array([1, 2, 3, 4, 5]);
-    /// v.retain(|e| *e % 2 == 1);
-    /// assert_eq!(v.as_slice(), &[1, 3, 5]);
+    /// let mut v: ArrayVec<String, 4> = ArrayVec::new();
+    /// v.push("a".to_string());
+    /// v.push("b".to_string());
+    /// v.extend_from_within(..1);
+    /// assert_eq!(v, ["a", "b", "a"]);
     /// ```
-    pub fn retain<F>(&mut self, mut f: F)
+    pub fn extend_from_within<R>(&mut self, range: R)
     where
-        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+        T: Clone,
     {
-        let len = self.len;
-        let mut del = 0;
-        {
-            let v = &mut **self;
-            for i in 0..len {
-                if !f(&mut v[i]) {
-                    del += 1;
-                } else if del > 0 {
-                    v.swap(i - del, i);
-                }
-            }
+        let (start, end) = match range_to_indices(range, self.len) {
+            Ok(indices) => indices,
+            Err(_) => panic!("range out of bounds"),
+        };
+        if end - start > self.remaining_capacity() {
+            panic!("capacity overflow");
         }
-        if del > 0 {
-            self.truncate(len - del);
+
+        for i in start..end {
+            let element = self[i].clone();
+            unsafe { self.try_push(element).unwrap_unchecked() };
         }
     }
 
-    // non-const because of trait
-
-    /// # Examples
+    /// Returns an iterator that removes and yields the elements in `range`
+    /// for which `filter` returns `true`.
+    ///
+    /// Elements that are not yielded are kept, including the ones that are
+    /// not inspected because the iterator is dropped early. The predicate may
+    /// modify the elements.
+    ///
+    /// Panics if the range is out of bounds. See [`ArrayVec::try_extract_if`].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     ///
-    /// let mut v = ArrayVec::from_array([1, 2, 3]);
-    /// {
-    ///     let mut iter = v.splice(..2, [7, 8].iter().copied()).unwrap();
-    ///     assert_eq!(iter.next().unwrap(), 1);
-    ///     assert_eq!(iter.next().unwrap(), 2);
-    /// }
-    /// assert_eq!(v.as_slice(), &[7, 8, 3]);
+    /// let mut v = ArrayVec::from_array([1, 2, 3, 4, 5, 6]);
+    /// let evens: ArrayVec<i32, 6> = v.extract_if(..4, |e| *e % 2 == 0).collect();
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(v, [1, 3, 5, 6]);
     /// ```
-    #[inline]
-    pub fn splice<I, R>(
-        &mut self,
-        range: R,
-        replace_with: I,
-    ) -> Option<Splice<'_, I::IntoIter, N>>
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, N>
     where
-        I: IntoIterator<Item = T>,
+        F: FnMut(&mut T) -> bool,
         R: RangeBounds<usize>,
     {
-        Some(Splice {
-            drain: self.drain(range)?,
-            replace_with: replace_with.into_iter(),
-        })
+        match self.try_extract_if(range, filter) {
+            Ok(extract_if) => extract_if,
+            Err(_) => panic!("range out of bounds"),
+        }
     }
 
-    /// # Examples
+    /// Lets `f` initialize a prefix of the spare capacity, and appends the
+    /// elements of that prefix.
+    ///
+    /// Unlike a closure that returns the number of elements it filled, `f`
+    /// returns the part it initialized as a `&mut [T]` starting at the
+    /// beginning of the spare capacity. Such a slice can only be produced
+    /// safely from initialized elements, so `f` cannot overstate how many
+    /// there are. The cost is that, until `MaybeUninit::write_copy_of_slice`
+    /// and similar methods are stable, `f` usually needs `unsafe` to turn the
+    /// elements it wrote into that slice, as in the example below.
+    ///
+    /// Returns the number of appended elements. Elements that `f` initializes
+    /// beyond the returned prefix are not dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the returned slice is not empty and does not start at the
+    /// beginning of the spare capacity, for example if `f` returns a subslice
+    /// that skips some elements or a slice of unrelated memory.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     ///
-    /// let mut v = ArrayVec::from_array([1, 2, 3]);
-    /// let v2 = v.split_off(1).unwrap();
-    /// assert_eq!(v.as_slice(), &[1]);
-    /// assert_eq!(v2.as_slice(), &[2, 3]);
+    /// let mut v: ArrayVec<u8, 4> = ArrayVec::from_partial_array([1]);
+    /// let src = [2, 3, 4, 5];
+    /// let n = v.fill_with_prefix(|spare| {
+    ///     let n = spare.len().min(src.len());
+    ///     for (slot, &byte) in spare.iter_mut().zip(&src[..n]) {
+    ///         slot.write(byte);
+    ///     }
+    ///     // SAFETY: The first `n` elements were initialized above.
+    ///     unsafe { &mut *(&mut spare[..n] as *mut [_] as *mut [u8]) }
+    /// });
+    /// assert_eq!(n, 3);
+    /// assert_eq!(v, [1, 2, 3, 4]);
     /// ```
-    pub const fn split_off(&mut self, at: usize) -> Option<Self> {
-        let len = self.len;
-        if at > len {
-            None
-        } else {
-            let mut other_arr_vec = Self::new();
-
-            self.len = at;
-            other_arr_vec.len = len - at;
+    pub fn fill_with_prefix<F>(&mut self, f: F) -> usize
+    where
+        F: FnOnce(&mut [MaybeUninit<T>]) -> &mut [T],
+    {
+        let spare = self.spare_capacity_mut();
+        let spare_ptr = spare.as_ptr() as *const T;
+        let spare_len = spare.len();
+
+        let filled = f(spare);
+        let n = filled.len();
+        if n > 0 && (!ptr::eq(filled.as_ptr(), spare_ptr) || n > spare_len) {
+            panic!("filled slice is not a prefix of the spare capacity");
+        }
 
-            unsafe {
-                self.as_ptr()
-                    .add(at)
-                    .copy_to_nonoverlapping(other_arr_vec.as_mut_ptr(), len - at);
-            }
+        // SAFETY: A safe `&mut [T]` over the first `n` spare elements proves
+        // that they are initialized.
+        self.len += n;
+        n
+    }
 
-            Some(other_arr_vec)
+    /// Inserts an element at position `idx`, shifting all elements after it to
+    /// the right.
+    ///
+    /// Panics if `idx > len` or if the vector is full. See
+    /// [`ArrayVec::try_insert`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::new();
+    /// v.push(2);
+    /// v.insert(0, 4);
+    /// v.insert(2, 6);
+    /// assert_eq!(v.as_slice(), &[4, 2, 6]);
+    /// ```
+    pub const fn insert(&mut self, idx: usize, element: T) {
+        if idx > self.len {
+            panic!("insertion index out of bounds");
         }
+        if self.len == N {
+            panic!("capacity overflow");
+        }
+
+        let ptr = unsafe { self.as_mut_ptr().add(idx) };
+        unsafe { ptr.copy_to(ptr.add(1), self.len - idx) };
+        unsafe { ptr.write(element) };
+        self.len += 1;
     }
 
-    /// # Examples
+    /// Copies the elements of `other` into position `idx`, shifting all
+    /// elements after it to the right, or fails without inserting any of them
+    /// if they do not all fit.
+    ///
+    /// Panics if `idx > len`.
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     ///
-    /// let mut v = ArrayVec::from_array([1, 2]);
-    /// assert!(v.swap_remove(10).is_none());
-    /// assert_eq!(v.swap_remove(0), Some(1));
-    ///
-    /// assert_eq!(v[0], 2);
-    /// assert_eq!(v.len(), 1);
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 4]);
+    /// assert_eq!(v.insert_from_copyable_slice(1, &[2, 3, 0]).unwrap_err().element(), &[2, 3, 0]);
+    /// assert!(v.insert_from_copyable_slice(1, &[2, 3]).is_ok());
+    /// assert_eq!(v, [1, 2, 3, 4]);
     /// ```
-    pub const fn swap_remove(&mut self, idx: usize) -> Option<T> {
-        if idx >= self.len {
-            return None;
+    pub const fn insert_from_copyable_slice<'a>(
+        &mut self,
+        idx: usize,
+        other: &'a [T],
+    ) -> Result<(), CapacityError<&'a [T]>>
+    where
+        T: Copy,
+    {
+        if idx > self.len {
+            panic!("insertion index out of bounds");
+        }
+        if other.len() > self.remaining_capacity() {
+            return Err(CapacityError::new(other));
         }
 
-        self.len -= 1;
+        let ptr = unsafe { self.as_mut_ptr().add(idx) };
+        unsafe { ptr.copy_to(ptr.add(other.len()), self.len - idx) };
+        unsafe { ptr::copy_nonoverlapping(other.as_ptr(), ptr, other.len()) };
+        self.len += other.len();
 
-        if self.len == 0 {
-            let v = unsafe { self.as_ptr().read() };
-            Some(v)
+        Ok(())
+    }
+
+    /// Clones the elements of `other` into position `idx`, shifting all
+    /// elements after it to the right.
+    ///
+    /// Panics if `idx > len` or if the elements do not all fit, without
+    /// inserting any of them. See [`ArrayVec::try_insert_slice`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 4]);
+    /// v.insert_from_slice(1, &[2, 3]);
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// ```
+    pub fn insert_from_slice(&mut self, idx: usize, other: &[T])
+    where
+        T: Clone,
+    {
+        if idx > self.len {
+            panic!("insertion index out of bounds");
+        }
+        if other.len() > self.remaining_capacity() {
+            panic!("capacity overflow");
+        }
+
+        // Removes the clones appended so far if a clone panics.
+        struct TruncateOnDrop<'a, T, const N: usize> {
+            vec: &'a mut ArrayVec<T, N>,
+            len: usize,
+        }
+
+        impl<'a, T, const N: usize> Drop for TruncateOnDrop<'a, T, N> {
+            fn drop(&mut self) {
+                self.vec.truncate(self.len);
+            }
+        }
+
+        // The clones are appended and then rotated into place, so the vector
+        // stays initialized up to its length throughout.
+        let guard = TruncateOnDrop {
+            len: self.len,
+            vec: self,
+        };
+        for element in other.iter().cloned() {
+            unsafe { guard.vec.try_push(element).unwrap_unchecked() };
+        }
+        mem::forget(guard);
+
+        self[idx..].rotate_right(other.len());
+    }
+
+    /// Converts a full vector into an array, or returns it unchanged if it is
+    /// not full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::from_partial_array([1, 2]);
+    /// v = v.into_inner().unwrap_err();
+    /// v.push(3);
+    /// assert_eq!(v.into_inner().unwrap(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub const fn into_inner(self) -> Result<[T; N], Self> {
+        if self.len < N {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+        unsafe {
+            // SAFETY: `ManuallyDrop` is `repr(transparent)`.
+            let this = &*(&this as *const ManuallyDrop<Self> as *const Self);
+            // SAFETY: All `N` elements are initialized.
+            Ok(ptr::read(&this.data).assume_init())
+        }
+    }
+
+    /// Removes and returns the element at position `idx`, shifting all
+    /// elements after it to the left.
+    ///
+    /// Panics if `idx` is out of bounds. See [`ArrayVec::try_remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert_eq!(v.remove(0), 1);
+    /// assert_eq!(v.as_slice(), &[2, 3]);
+    /// ```
+    pub const fn remove(&mut self, idx: usize) -> T {
+        if idx >= self.len {
+            panic!("removal index out of bounds");
+        }
+
+        let ptr = unsafe { self.as_mut_ptr().add(idx) };
+        let result = unsafe { ptr.read() };
+        unsafe { ptr.copy_from(ptr.add(1), self.len - idx - 1) };
+        self.len -= 1;
+
+        result
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// clones of `value`.
+    ///
+    /// Panics if `new_len > N`. See [`ArrayVec::try_resize`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1]);
+    /// v.resize(3, 0);
+    /// assert_eq!(v, [1, 0, 0]);
+    /// v.resize(2, 5);
+    /// assert_eq!(v, [1, 0]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len > N {
+            panic!("capacity overflow");
+        }
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        for _ in self.len + 1..new_len {
+            unsafe { self.try_push(value.clone()).unwrap_unchecked() };
+        }
+        unsafe { self.try_push(value).unwrap_unchecked() };
+    }
+
+    /// Moves the elements into a vector with a capacity of `M`, which must be
+    /// at least `N`. This is checked at compile time.
+    ///
+    /// Use [`ArrayVec::try_resize_capacity`] to move them into a smaller
+    /// vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::from_array([1, 2]);
+    /// let mut v: ArrayVec<i32, 4> = v.resize_capacity();
+    /// v.push(3);
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::from_array([1, 2]);
+    /// let v: ArrayVec<i32, 1> = v.resize_capacity();
+    /// ```
+    #[inline]
+    pub const fn resize_capacity<const M: usize>(self) -> ArrayVec<T, M> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertCapacity::<N, M>::WIDENS;
+
+        unsafe { self.move_into() }
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// default values.
+    ///
+    /// Panics if `new_len > N`. See [`ArrayVec::try_resize_default`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1]);
+    /// v.resize_default(3);
+    /// assert_eq!(v, [1, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn resize_default(&mut self, new_len: usize)
+    where
+        T: Default,
+    {
+        self.resize_with(new_len, T::default);
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// values returned by `f`.
+    ///
+    /// Panics if `new_len > N`. See [`ArrayVec::try_resize_with`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut next = 0;
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::new();
+    /// v.resize_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        if new_len > N {
+            panic!("capacity overflow");
+        }
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        while self.len < new_len {
+            unsafe { self.try_push(f()).unwrap_unchecked() };
+        }
+    }
+
+    // Can't be const because of drop and trait methods
+
+    /// Retains only the elements specified by the predicate, in a single
+    /// pass that moves each kept element at most once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3, 4, 5]);
+    /// v.retain(|e| *e % 2 == 1);
+    /// assert_eq!(v.as_slice(), &[1, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|element| f(element));
+    }
+
+    /// Like [`ArrayVec::retain`], but passes a mutable reference to the
+    /// predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3, 4]);
+    /// v.retain_mut(|e| {
+    ///     *e *= 10;
+    ///     *e > 20
+    /// });
+    /// assert_eq!(v, [30, 40]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.extract_if(.., |element| !f(element)).for_each(drop);
+    }
+
+    /// Returns the unused capacity after the elements, to be initialized
+    /// before committing them with [`ArrayVec::set_len`].
+    ///
+    /// See [`ArrayVec::fill_with_prefix`] for a safe way to do both.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1]);
+    /// let spare = v.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 3);
+    /// spare[0].write(2);
+    /// unsafe { v.set_len(2) };
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    #[inline]
+    pub const fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self.split_at_spare_mut().1
+    }
+
+    // non-const because of trait
+
+    /// Removes the elements in `range` and replaces them with the elements of
+    /// `replace_with` once the returned [`Splice`] is dropped.
+    ///
+    /// The replacement is limited to the fixed capacity: replacement elements
+    /// that do not fit are dropped when the `Splice` is dropped. Use
+    /// [`Splice::try_finish`] to detect overflow instead.
+    ///
+    /// Panics if the range is out of bounds. See [`ArrayVec::try_splice`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// {
+    ///     let mut iter = v.splice(..2, [7, 8].iter().copied());
+    ///     assert_eq!(iter.next().unwrap(), 1);
+    ///     assert_eq!(iter.next().unwrap(), 2);
+    /// }
+    /// assert_eq!(v.as_slice(), &[7, 8, 3]);
+    /// ```
+    #[inline]
+    #[must_use = "replacement elements that do not fit are dropped; use `Splice::try_finish` to detect overflow"]
+    pub fn splice<I, R>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, N>
+    where
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
+    {
+        Splice {
+            drain: self.drain(range),
+            replace_with: replace_with.into_iter(),
+        }
+    }
+
+    /// Returns the elements and the unused capacity after them, so that the
+    /// elements can be read while the spare capacity is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 2]);
+    /// let (init, spare) = v.split_at_spare_mut();
+    /// let sum = init.iter().sum();
+    /// spare[0].write(sum);
+    /// unsafe { v.set_len(3) };
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub const fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let len = self.len;
+        let ptr = self.as_mut_ptr();
+        // SAFETY: The first `len` elements are initialized and the rest of
+        // the buffer does not overlap with them.
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, len),
+                slice::from_raw_parts_mut(ptr.add(len) as *mut MaybeUninit<T>, N - len),
+            )
+        }
+    }
+
+    /// Splits the vector in two at `at`, returning the elements from `at`
+    /// onwards in a new vector.
+    ///
+    /// Panics if `at > len`. See [`ArrayVec::try_split_off`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// let v2 = v.split_off(1);
+    /// assert_eq!(v.as_slice(), &[1]);
+    /// assert_eq!(v2.as_slice(), &[2, 3]);
+    /// ```
+    pub const fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        if at > len {
+            panic!("split index out of bounds");
+        }
+
+        let mut other_arr_vec = Self::new();
+
+        self.len = at;
+        other_arr_vec.len = len - at;
+
+        unsafe {
+            self.as_ptr()
+                .add(at)
+                .copy_to_nonoverlapping(other_arr_vec.as_mut_ptr(), len - at);
+        }
+
+        other_arr_vec
+    }
+
+    /// Removes and returns the element at position `idx`, replacing it with
+    /// the last element.
+    ///
+    /// Panics if `idx` is out of bounds. See [`ArrayVec::try_swap_remove`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert_eq!(v.swap_remove(0), 1);
+    /// assert_eq!(v.as_slice(), &[3, 2]);
+    /// ```
+    pub const fn swap_remove(&mut self, idx: usize) -> T {
+        if idx >= self.len {
+            panic!("swap_remove index out of bounds");
+        }
+
+        self.len -= 1;
+
+        if self.len == 0 {
+            unsafe { self.as_ptr().read() }
+        } else {
+            let last = unsafe { self.as_ptr().add(self.len).read() };
+            let hole = unsafe { self.as_mut_ptr().add(idx) };
+
+            let v = unsafe { hole.read() };
+            unsafe { hole.write(last) };
+
+            v
+        }
+    }
+
+    /// Removes the last `M` elements and returns them as an array, or returns
+    /// [`None`] if there are fewer than `M`.
+    ///
+    /// This does not move the remaining elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert_eq!(v.take_back_array(), Some([2, 3]));
+    /// assert_eq!(v.take_back_array::<2>(), None);
+    /// assert_eq!(v, [1]);
+    /// ```
+    pub const fn take_back_array<const M: usize>(&mut self) -> Option<[T; M]> {
+        if self.len < M {
+            return None;
+        }
+
+        self.len -= M;
+        // SAFETY: The `M` elements after the new length are initialized and
+        // no longer part of the vector.
+        Some(unsafe { (self.as_ptr().add(self.len) as *const [T; M]).read() })
+    }
+
+    /// Removes the first `M` elements and returns them as an array, or
+    /// returns [`None`] if there are fewer than `M`.
+    ///
+    /// The remaining elements are moved to the front.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert_eq!(v.take_front_array(), Some([1, 2]));
+    /// assert_eq!(v.take_front_array::<2>(), None);
+    /// assert_eq!(v, [3]);
+    /// ```
+    pub const fn take_front_array<const M: usize>(&mut self) -> Option<[T; M]> {
+        if self.len < M {
+            return None;
+        }
+
+        self.len -= M;
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            // SAFETY: The first `M` elements are initialized, and are
+            // overwritten by the copy below.
+            let array = (ptr as *const [T; M]).read();
+            ptr::copy(ptr.add(M), ptr, self.len);
+            Some(array)
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2]);
+    /// v.truncate(1);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len > self.len {
+            return;
+        }
+        let remaining_len = self.len - len;
+        let s = unsafe { ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), remaining_len) };
+        self.len = len;
+        unsafe { ptr::drop_in_place(s) };
+    }
+
+    /// Moves all the elements of `other` to the end of the vector, leaving
+    /// `other` empty, or fails if they do not all fit.
+    ///
+    /// Both vectors are left unchanged on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::from_partial_array([1, 2]);
+    /// let mut other = ArrayVec::from_array([3, 4]);
+    /// assert!(v.try_append(&mut other).is_err());
+    /// assert_eq!((v.len(), other.len()), (2, 2));
+    ///
+    /// other.pop();
+    /// assert!(v.try_append(&mut other).is_ok());
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    pub const fn try_append<const M: usize>(
+        &mut self,
+        other: &mut ArrayVec<T, M>,
+    ) -> Result<(), CapacityError> {
+        if other.len > self.remaining_capacity() {
+            Err(CapacityError::new(()))
+        } else {
+            self.append(other);
+            Ok(())
+        }
+    }
+
+    /// Removes the elements in `range` from the vector, returning them as an
+    /// iterator, or fails if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert!(v.try_drain(2..4).is_err());
+    /// assert!(v.try_drain(2..1).is_err());
+    /// assert!(v.try_drain(1..).unwrap().eq([2, 3]));
+    /// assert_eq!(v.as_slice(), &[1]);
+    /// ```
+    pub fn try_drain<R>(&mut self, range: R) -> Result<Drain<'_, T, N>, OutOfBoundsError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let len = self.len;
+        let (start, end) = range_to_indices(range, len)?;
+
+        // set self.vec length's to start, to be safe in case Drain is leaked
+        self.len = start;
+
+        // Use the borrow in the IterMut to indicate borrowing behavior of the
+        // whole Drain iterator (like &mut T).
+        let range_slice = unsafe { slice::from_raw_parts(self.as_ptr().add(start), end - start) };
+
+        Ok(Drain {
+            tail_start: end,
+            tail_len: len - end,
+            iter: range_slice.iter(),
+            vec: NonNull::from(self),
+        })
+    }
+
+    /// Extends the vector with the contents of an iterator, failing if they do
+    /// not all fit.
+    ///
+    /// The elements that fit are kept. On overflow, an iterator over the
+    /// elements that did not fit is returned; its first element is the one
+    /// taken from `iter` to detect the overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::new();
+    /// assert!(v.try_extend(1..=2).is_ok());
+    ///
+    /// let rest = v.try_extend(3..=6).unwrap_err().element();
+    /// assert_eq!(v, [1, 2, 3, 4]);
+    /// assert!(rest.eq(5..=6));
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), CapacityError<Remaining<T, I::IntoIter>>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        while self.len < N {
+            match iter.next() {
+                Some(element) => unsafe { self.try_push(element).unwrap_unchecked() },
+                None => return Ok(()),
+            }
+        }
+        match iter.next() {
+            Some(element) => Err(CapacityError::new(iter::once(element).chain(iter))),
+            None => Ok(()),
+        }
+    }
+
+    /// Clones the elements in `range` and appends them, or fails without
+    /// appending any of them if they do not all fit.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 2, 3]);
+    /// assert!(v.try_extend_from_within(1..).is_err());
+    /// assert!(v.try_extend_from_within(2..).is_ok());
+    /// assert_eq!(v, [1, 2, 3, 3]);
+    /// ```
+    pub fn try_extend_from_within<R>(&mut self, range: R) -> Result<(), CapacityError>
+    where
+        R: RangeBounds<usize>,
+        T: Clone,
+    {
+        let (start, end) = match range_to_indices(range, self.len) {
+            Ok(indices) => indices,
+            Err(_) => panic!("range out of bounds"),
+        };
+        if end - start > self.remaining_capacity() {
+            Err(CapacityError::new(()))
+        } else {
+            self.extend_from_within(start..end);
+            Ok(())
+        }
+    }
+
+    /// Like [`ArrayVec::extract_if`], but fails if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert!(v.try_extract_if(2..4, |_| true).is_err());
+    /// assert!(v.try_extract_if(1.., |_| true).unwrap().eq([2, 3]));
+    /// assert_eq!(v, [1]);
+    /// ```
+    pub fn try_extract_if<F, R>(
+        &mut self,
+        range: R,
+        filter: F,
+    ) -> Result<ExtractIf<'_, T, F, N>, OutOfBoundsError>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = range_to_indices(range, self.len)?;
+        Ok(ExtractIf::new(self, start, end, filter))
+    }
+
+    /// Inserts an element at position `idx`, shifting all elements after it to
+    /// the right, or returns it in a [`CapacityError`] if the vector is full.
+    ///
+    /// Panics if `idx > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    /// let mut v: ArrayVec<i32, 2> = ArrayVec::new();
+    /// v.push(2);
+    ///
+    /// assert!(v.try_insert(0, 4).is_ok());
+    /// assert_eq!(v.as_slice(), &[4, 2]);
+    ///
+    /// // Full capacity
+    /// assert_eq!(v.try_insert(0, 6).unwrap_err().element(), 6);
+    /// ```
+    pub const fn try_insert(&mut self, idx: usize, element: T) -> Result<(), CapacityError<T>> {
+        if idx > self.len {
+            panic!("insertion index out of bounds");
+        }
+        if self.len == N {
+            return Err(CapacityError::new(element));
+        }
+
+        let ptr = unsafe { self.as_mut_ptr().add(idx) };
+        unsafe { ptr.copy_to(ptr.add(1), self.len - idx) };
+        unsafe { ptr.write(element) };
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Clones the elements of `other` into position `idx`, shifting all
+    /// elements after it to the right, or fails without inserting any of them
+    /// if they do not all fit.
+    ///
+    /// Panics if `idx > len`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::from_partial_array([1, 4]);
+    /// assert_eq!(v.try_insert_slice(1, &[2, 3]).unwrap_err().element(), &[2, 3]);
+    /// assert!(v.try_insert_slice(1, &[2]).is_ok());
+    /// assert_eq!(v, [1, 2, 4]);
+    /// ```
+    pub fn try_insert_slice<'a>(
+        &mut self,
+        idx: usize,
+        other: &'a [T],
+    ) -> Result<(), CapacityError<&'a [T]>>
+    where
+        T: Clone,
+    {
+        if idx > self.len {
+            panic!("insertion index out of bounds");
+        }
+        if other.len() > self.remaining_capacity() {
+            Err(CapacityError::new(other))
         } else {
-            let last = unsafe { self.as_ptr().add(self.len).read() };
-            let hole = unsafe { self.as_mut_ptr().add(idx) };
+            self.insert_from_slice(idx, other);
+            Ok(())
+        }
+    }
 
-            let v = unsafe { hole.read() };
-            unsafe { hole.write(last) };
+    /// Removes and returns the element at position `idx`, shifting all
+    /// elements after it to the left, or fails if `idx` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert!(v.try_remove(10).is_err());
+    /// assert_eq!(v.try_remove(0), Ok(1));
+    /// assert_eq!(v.as_slice(), &[2, 3]);
+    /// ```
+    pub const fn try_remove(&mut self, idx: usize) -> Result<T, OutOfBoundsError> {
+        if idx >= self.len {
+            Err(OutOfBoundsError::new())
+        } else {
+            Ok(self.remove(idx))
+        }
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// clones of `value`, or returns `value` in a [`CapacityError`] if
+    /// `new_len > N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::from_partial_array([1]);
+    /// assert_eq!(v.try_resize(4, 0).unwrap_err().element(), 0);
+    /// assert!(v.try_resize(3, 0).is_ok());
+    /// assert_eq!(v, [1, 0, 0]);
+    /// ```
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), CapacityError<T>>
+    where
+        T: Clone,
+    {
+        if new_len > N {
+            Err(CapacityError::new(value))
+        } else {
+            self.resize(new_len, value);
+            Ok(())
+        }
+    }
+
+    /// Moves the elements into a vector with a capacity of `M`, or returns
+    /// the vector unchanged if they do not fit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v: ArrayVec<i32, 8> = ArrayVec::from_partial_array([1, 2, 3]);
+    /// let v = v.try_resize_capacity::<2>().unwrap_err();
+    /// let v: ArrayVec<i32, 3> = v.try_resize_capacity().unwrap();
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub const fn try_resize_capacity<const M: usize>(self) -> Result<ArrayVec<T, M>, Self> {
+        if self.len > M {
+            Err(self)
+        } else {
+            Ok(unsafe { self.move_into() })
+        }
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// default values, or fails if `new_len > N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::from_partial_array([1]);
+    /// assert!(v.try_resize_default(4).is_err());
+    /// assert!(v.try_resize_default(2).is_ok());
+    /// assert_eq!(v, [1, 0]);
+    /// ```
+    pub fn try_resize_default(&mut self, new_len: usize) -> Result<(), CapacityError>
+    where
+        T: Default,
+    {
+        self.try_resize_with(new_len, T::default)
+            .map_err(CapacityError::simplify)
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// values returned by `f`, or returns `f` in a [`CapacityError`] if
+    /// `new_len > N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 3> = ArrayVec::new();
+    /// assert!(v.try_resize_with(4, || 1).is_err());
+    /// assert!(v.try_resize_with(3, || 1).is_ok());
+    /// assert_eq!(v, [1, 1, 1]);
+    /// ```
+    pub fn try_resize_with<F>(&mut self, new_len: usize, f: F) -> Result<(), CapacityError<F>>
+    where
+        F: FnMut() -> T,
+    {
+        if new_len > N {
+            Err(CapacityError::new(f))
+        } else {
+            self.resize_with(new_len, f);
+            Ok(())
+        }
+    }
+
+    /// Like [`ArrayVec::splice`], but fails if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert!(v.try_splice(2..4, [7, 8].iter().copied()).is_err());
+    /// assert!(v.try_splice(..2, [7, 8].iter().copied()).unwrap().eq([1, 2]));
+    /// assert_eq!(v.as_slice(), &[7, 8, 3]);
+    /// ```
+    #[inline]
+    pub fn try_splice<I, R>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Splice<'_, I::IntoIter, N>, OutOfBoundsError>
+    where
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
+    {
+        Ok(Splice {
+            drain: self.try_drain(range)?,
+            replace_with: replace_with.into_iter(),
+        })
+    }
 
-            Some(v)
+    /// Splits the vector in two at `at`, returning the elements from `at`
+    /// onwards in a new vector, or fails if `at > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from_array([1, 2, 3]);
+    /// assert!(v.try_split_off(4).is_err());
+    /// let v2 = v.try_split_off(1).unwrap();
+    /// assert_eq!(v.as_slice(), &[1]);
+    /// assert_eq!(v2.as_slice(), &[2, 3]);
+    /// ```
+    pub const fn try_split_off(&mut self, at: usize) -> Result<Self, OutOfBoundsError> {
+        if at > self.len {
+            Err(OutOfBoundsError::new())
+        } else {
+            Ok(self.split_off(at))
         }
     }
 
+    /// Removes and returns the element at position `idx`, replacing it with
+    /// the last element, or fails if `idx` is out of bounds.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// use stack_based_vec::ArrayVec;
     ///
     /// let mut v = ArrayVec::from_array([1, 2]);
-    /// v.truncate(1);
+    /// assert!(v.try_swap_remove(10).is_err());
+    /// assert_eq!(v.try_swap_remove(0), Ok(1));
+    ///
+    /// assert_eq!(v[0], 2);
     /// assert_eq!(v.len(), 1);
     /// ```
-    #[inline]
-    pub fn truncate(&mut self, len: usize) {
-        if len > self.len {
-            return;
+    pub const fn try_swap_remove(&mut self, idx: usize) -> Result<T, OutOfBoundsError> {
+        if idx >= self.len {
+            Err(OutOfBoundsError::new())
+        } else {
+            Ok(self.swap_remove(idx))
         }
-        let remaining_len = self.len - len;
-        let s = unsafe { ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), remaining_len) };
-        self.len = len;
-        unsafe { ptr::drop_in_place(s) };
+    }
+
+    /// Moves the elements into a vector with a capacity of `M`.
+    ///
+    /// # Safety
+    ///
+    /// `len` cannot exceed `M`.
+    const unsafe fn move_into<const M: usize>(self) -> ArrayVec<T, M> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `ManuallyDrop` is `repr(transparent)`.
+        let this = &*(&this as *const ManuallyDrop<Self> as *const Self);
+
+        let mut v = ArrayVec::new();
+        ptr::copy_nonoverlapping(this.as_ptr(), v.as_mut_ptr(), this.len);
+        v.len = this.len;
+        v
     }
 
     #[inline]
@@ -749,31 +1967,86 @@ impl<T, const N: usize> ArrayVec<T, N> {
     }
 }
 
-impl<T, const N: usize> const AsRef<[T]> for ArrayVec<T, N> {
-    #[inline]
-    fn as_ref(&self) -> &[T] {
-        self
+/// Compile-time checks on pairs of capacities.
+struct AssertCapacity<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> AssertCapacity<N, M> {
+    const WIDENS: () = assert!(M >= N, "the new capacity is smaller than the old one");
+}
+
+/// Converts `range` into a `start..end` pair of indices, checking it against
+/// `len`.
+pub(crate) fn range_to_indices<R>(range: R, len: usize) -> Result<(usize, usize), OutOfBoundsError>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&n) => Some(n),
+        Bound::Excluded(&n) => n.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1),
+        Bound::Excluded(&n) => Some(n),
+        Bound::Unbounded => Some(len),
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= len => Ok((start, end)),
+        _ => Err(OutOfBoundsError::new()),
     }
 }
 
-impl<T, const N: usize> const AsMut<[T]> for ArrayVec<T, N> {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [T] {
-        self
+/// Implements a trait for `ArrayVec<T, N>`, as a `const` impl when the
+/// `nightly` feature is enabled.
+#[cfg(feature = "nightly")]
+macro_rules! impl_maybe_const {
+    ($trait:path { $($body:tt)* }) => {
+        impl<T, const N: usize> const $trait for ArrayVec<T, N> { $($body)* }
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+macro_rules! impl_maybe_const {
+    ($trait:path { $($body:tt)* }) => {
+        impl<T, const N: usize> $trait for ArrayVec<T, N> { $($body)* }
+    };
+}
+
+impl_maybe_const! {
+    AsRef<[T]> {
+        #[inline]
+        fn as_ref(&self) -> &[T] {
+            self
+        }
     }
 }
 
-impl<T, const N: usize> const Borrow<[T]> for ArrayVec<T, N> {
-    #[inline]
-    fn borrow(&self) -> &[T] {
-        self
+impl_maybe_const! {
+    AsMut<[T]> {
+        #[inline]
+        fn as_mut(&mut self) -> &mut [T] {
+            self
+        }
     }
 }
 
-impl<T, const N: usize> const BorrowMut<[T]> for ArrayVec<T, N> {
-    #[inline]
-    fn borrow_mut(&mut self) -> &mut [T] {
-        self
+impl_maybe_const! {
+    Borrow<[T]> {
+        #[inline]
+        fn borrow(&self) -> &[T] {
+            self
+        }
+    }
+}
+
+impl_maybe_const! {
+    BorrowMut<[T]> {
+        #[inline]
+        fn borrow_mut(&mut self) -> &mut [T] {
+            self
+        }
     }
 }
 
@@ -802,32 +2075,47 @@ where
     }
 }
 
-impl<T, const N: usize> const Copy for ArrayVec<T, N> where T: ~const Copy {}
+impl_maybe_const! {
+    Default {
+        #[inline]
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
 
-impl<T, const N: usize> const Default for ArrayVec<T, N> {
-    #[inline]
-    fn default() -> Self {
-        Self::new()
+impl_maybe_const! {
+    Deref {
+        type Target = [T];
+        #[inline]
+        fn deref(&self) -> &Self::Target {
+            self.as_slice()
+        }
     }
 }
 
-impl<T, const N: usize> const Deref for ArrayVec<T, N> {
-    type Target = [T];
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        self.as_slice()
+impl_maybe_const! {
+    DerefMut {
+        #[inline]
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            self.as_mut_slice()
+        }
     }
 }
 
-impl<T, const N: usize> const DerefMut for ArrayVec<T, N> {
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.as_mut_slice()
+    fn drop(&mut self) {
+        // Dropping a slice in place keeps dropping the remaining elements
+        // even if one of the destructors panics.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
     }
 }
 
 impl<T, const N: usize> Eq for ArrayVec<T, N> where T: Eq {}
 
+/// Elements past the capacity are not taken from the iterator and are
+/// silently ignored. Use [`ArrayVec::try_extend`] to detect overflow.
 impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
     #[inline]
     fn extend<I>(&mut self, iter: I)
@@ -836,15 +2124,64 @@ impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
     {
         let remaining_capacity = self.remaining_capacity();
         for element in iter.into_iter().take(remaining_capacity) {
-            let _ = self.push(element);
+            self.push(element);
+        }
+    }
+}
+
+impl_maybe_const! {
+    From<[T; N]> {
+        #[inline]
+        fn from(from: [T; N]) -> Self {
+            Self::from_array(from)
+        }
+    }
+}
+
+/// Panics if the iterator yields more than `N` elements. Use
+/// [`ArrayVec::try_from_iter`] to handle overflow instead.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayVec;
+///
+/// let v: ArrayVec<i32, 8> = (1..=4).map(|i| i * i).collect();
+/// assert_eq!(v, [1, 4, 9, 16]);
+/// ```
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N> {
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        match Self::try_from_iter(iter) {
+            Ok(v) => v,
+            Err(_) => panic!("capacity overflow"),
         }
     }
 }
 
-impl<T, const N: usize> const From<[T; N]> for ArrayVec<T, N> {
+/// Hashes exactly like the equivalent `[T]`, so that a map keyed by
+/// `ArrayVec` can be queried with slices through `Borrow<[T]>`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use stack_based_vec::ArrayVec;
+///
+/// let mut set = HashSet::new();
+/// set.insert(ArrayVec::<u8, 4>::from_partial_array(*b"key"));
+/// assert!(set.contains(&b"key"[..]));
+/// ```
+impl<T, const N: usize> Hash for ArrayVec<T, N>
+where
+    T: Hash,
+{
     #[inline]
-    fn from(from: [T; N]) -> Self {
-        Self::from_array(from)
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self.as_slice(), state)
     }
 }
 
@@ -870,6 +2207,38 @@ where
     }
 }
 
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    /// Creates a consuming iterator, that is, one that moves each value out of
+    /// the vector (from start to end). The vector cannot be used after calling
+    /// this.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let v = ArrayVec::from_array(["a".to_string(), "b".to_string()]);
+    /// for s in v {
+    ///     // s has type String, not &String
+    ///     println!("{}", s);
+    /// }
+    /// ```
+    #[inline]
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.len;
+        // The iterator takes over dropping `0..end`.
+        self.len = 0;
+        IntoIter {
+            vec: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
 impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
     type IntoIter = Iter<'a, T>;
     type Item = &'a T;
@@ -984,6 +2353,56 @@ where
     }
 }
 
+/// Clones the elements of a slice into a new vector, or fails if there are
+/// more than `N`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use stack_based_vec::ArrayVec;
+///
+/// let v = ArrayVec::<i32, 4>::try_from(&[1, 2][..]).unwrap();
+/// assert_eq!(v, [1, 2]);
+/// assert!(ArrayVec::<i32, 1>::try_from(&[1, 2][..]).is_err());
+/// ```
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for ArrayVec<T, N>
+where
+    T: Clone,
+{
+    type Error = CapacityError<&'a [T]>;
+
+    fn try_from(slice: &'a [T]) -> Result<Self, Self::Error> {
+        if slice.len() > N {
+            return Err(CapacityError::new(slice));
+        }
+
+        let mut v = Self::new();
+        v.extend_from_slice(slice);
+        Ok(v)
+    }
+}
+
+/// Converts a full vector into an array. See [`ArrayVec::into_inner`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use stack_based_vec::ArrayVec;
+///
+/// let v = ArrayVec::from_array([1, 2]);
+/// assert_eq!(<[i32; 2]>::try_from(v).unwrap(), [1, 2]);
+/// ```
+impl<T, const N: usize> TryFrom<ArrayVec<T, N>> for [T; N] {
+    type Error = ArrayVec<T, N>;
+
+    #[inline]
+    fn try_from(vec: ArrayVec<T, N>) -> Result<Self, Self::Error> {
+        vec.into_inner()
+    }
+}
+
 impl<T, const N: usize> fmt::Debug for ArrayVec<T, N>
 where
     T: fmt::Debug,
@@ -993,3 +2412,48 @@ where
         f.debug_list().entries(self).finish()
     }
 }
+
+/// Appends the UTF-8 encoding of the formatted output.
+///
+/// If a string slice does not fit, the longest prefix of it that ends on a
+/// `char` boundary is appended and [`fmt::Error`] is returned, so the bytes
+/// written so far are always valid UTF-8. Unlike [`ArrayString`], which
+/// rejects such a slice as a whole, this keeps as much of the output as
+/// fits.
+///
+/// # Example
+///
+/// ```rust
+/// use core::fmt::Write;
+/// use stack_based_vec::ArrayVec;
+///
+/// let mut buf: ArrayVec<u8, 4> = ArrayVec::new();
+/// assert!(write!(buf, "ab{}", 'é').is_ok());
+/// assert_eq!(buf, *b"ab\xc3\xa9");
+///
+/// let mut buf: ArrayVec<u8, 4> = ArrayVec::new();
+/// assert!(write!(buf, "abc{}", 'é').is_err());
+/// assert_eq!(buf, *b"abc");
+///
+/// let mut buf: ArrayVec<u8, 4> = ArrayVec::new();
+/// assert!(write!(buf, "{}", "aé€").is_err());
+/// assert_eq!(buf, "aé".as_bytes());
+/// ```
+impl<const N: usize> fmt::Write for ArrayVec<u8, N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let (fits, result) = if s.len() <= self.remaining_capacity() {
+            (s.len(), Ok(()))
+        } else {
+            let mut end = self.remaining_capacity();
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            (end, Err(fmt::Error))
+        };
+
+        match self.extend_from_copyable_slice(&s.as_bytes()[..fits]) {
+            Ok(()) => result,
+            Err(_) => unreachable!(),
+        }
+    }
+}
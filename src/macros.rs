@@ -25,3 +25,57 @@ macro_rules! array_vec {
     ($elem:expr; $n:expr) => ($crate::ArrayVec::from_partial_array([$elem; $n]));
     ($($elem:expr),+ $(,)?) => ($crate::ArrayVec::from_partial_array([$($elem),+]));
 }
+
+/// Creates an [`crate::ArrayString`] containing the given string.
+///
+/// Panics if the string does not fit. The capacity is usually inferred, and
+/// the macro can be used in constants.
+///
+/// # Examples
+///
+/// ```
+/// use stack_based_vec::*;
+///
+/// let s: ArrayString<8> = array_string!("hello");
+/// assert_eq!(s.capacity(), 8);
+/// assert_eq!(s, "hello");
+///
+/// let empty: ArrayString<8> = array_string!();
+/// assert!(empty.is_empty());
+/// ```
+#[macro_export]
+macro_rules! array_string {
+    () => {
+        $crate::ArrayString::new()
+    };
+    ($s:expr $(,)?) => {
+        $crate::ArrayString::copy_from_str($s)
+    };
+}
+
+/// Formats the arguments into an [`crate::ArrayString`], without allocating.
+///
+/// Returns [`core::fmt::Error`] if the output does not fit, or if a
+/// formatting trait implementation fails. The capacity is usually inferred.
+///
+/// # Examples
+///
+/// ```
+/// use stack_based_vec::*;
+///
+/// let s: ArrayString<16> = array_format!("{}-{:02}", "id", 7).unwrap();
+/// assert_eq!(s, "id-07");
+///
+/// let too_long: Result<ArrayString<4>, _> = array_format!("{}", 123_456);
+/// assert!(too_long.is_err());
+/// ```
+#[macro_export]
+macro_rules! array_format {
+    ($($arg:tt)*) => {{
+        let mut s = $crate::ArrayString::new();
+        match ::core::fmt::Write::write_fmt(&mut s, ::core::format_args!($($arg)*)) {
+            Ok(()) => Ok(s),
+            Err(e) => Err(e),
+        }
+    }};
+}
@@ -0,0 +1,188 @@
+//! A collector that samples items uniformly from a stream.
+
+use core::{fmt, mem};
+
+use crate::ArrayVec;
+
+/// A source of uniformly distributed random numbers for [`Reservoir`].
+///
+/// It is implemented by closures, so any generator can be plugged in without
+/// depending on a particular crate.
+pub trait Rng {
+    /// Returns the next random `u64`, with all values equally likely.
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<F> Rng for F
+where
+    F: FnMut() -> u64,
+{
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self()
+    }
+}
+
+/// Keeps a uniform random sample of `N` of the items pushed into it.
+///
+/// After `n` pushes, every item has been kept with probability `N / n`, or
+/// `1` if fewer than `N` items were pushed. The sample is kept in an
+/// `ArrayVec<T, N>` and each push takes constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::Reservoir;
+///
+/// let mut state = 0x2545_f491_4f6c_dd1d_u64;
+/// let xorshift = move || {
+///     state ^= state << 13;
+///     state ^= state >> 7;
+///     state ^= state << 17;
+///     state
+/// };
+/// let mut sample: Reservoir<u32, 3, _> = Reservoir::new(xorshift);
+/// sample.extend(0..100);
+/// assert_eq!(sample.seen(), 100);
+/// assert!(sample.into_vec().iter().all(|&x| x < 100));
+/// ```
+#[derive(Clone)]
+pub struct Reservoir<T, const N: usize, R> {
+    rng: R,
+    sample: ArrayVec<T, N>,
+    seen: u64,
+}
+
+impl<T, const N: usize, R> Reservoir<T, N, R>
+where
+    R: Rng,
+{
+    // Constructors
+
+    /// Constructs a new, empty reservoir that draws from `rng`.
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng,
+            sample: ArrayVec::new(),
+            seen: 0,
+        }
+    }
+
+    // Methods
+
+    /// Returns the sampled items, in an unspecified order.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.sample.as_slice()
+    }
+
+    /// Returns the number of items that are sampled.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the sample and the random number generator.
+    #[inline]
+    pub fn into_parts(self) -> (ArrayVec<T, N>, R) {
+        (self.sample, self.rng)
+    }
+
+    /// Returns the sampled items, in an unspecified order.
+    #[inline]
+    pub fn into_vec(self) -> ArrayVec<T, N> {
+        self.sample
+    }
+
+    /// Returns `true` if no items are sampled.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.sample.is_empty()
+    }
+
+    /// Returns the number of sampled items, which is the smaller of `N` and
+    /// [`Reservoir::seen`].
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.sample.len()
+    }
+
+    /// Offers `item` to the sample, returning the item that is not kept, if
+    /// any. This is either `item` itself or a previously sampled item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::Reservoir;
+    ///
+    /// let mut sample: Reservoir<char, 1, _> = Reservoir::new(|| u64::MAX);
+    /// assert_eq!(sample.push('a'), None);
+    /// assert!(sample.push('b').is_some());
+    /// assert_eq!(sample.len(), 1);
+    /// ```
+    pub fn push(&mut self, item: T) -> Option<T> {
+        self.seen = self.seen.saturating_add(1);
+        if let Err(err) = self.sample.try_push(item) {
+            let item = err.element();
+            let idx = uniform_below(&mut self.rng, self.seen);
+            return if idx < N as u64 {
+                Some(mem::replace(&mut self.sample[idx as usize], item))
+            } else {
+                Some(item)
+            };
+        }
+        None
+    }
+
+    /// Returns the number of items pushed so far.
+    #[inline]
+    pub const fn seen(&self) -> u64 {
+        self.seen
+    }
+}
+
+impl<T, const N: usize, R> Extend<T> for Reservoir<T, N, R>
+where
+    R: Rng,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| {
+            let _ = self.push(item);
+        });
+    }
+}
+
+impl<T, const N: usize, R> fmt::Debug for Reservoir<T, N, R>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reservoir")
+            .field("sample", &self.sample)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+/// Returns a uniformly distributed number in `0..n`, where `n > 0`.
+///
+/// This is Lemire's multiply-and-reject method, which avoids the bias of
+/// `next_u64() % n`.
+fn uniform_below<R>(rng: &mut R, n: u64) -> u64
+where
+    R: Rng,
+{
+    let mut m = u128::from(rng.next_u64()) * u128::from(n);
+    if (m as u64) < n {
+        let threshold = n.wrapping_neg() % n;
+        while (m as u64) < threshold {
+            m = u128::from(rng.next_u64()) * u128::from(n);
+        }
+    }
+    (m >> 64) as u64
+}
@@ -0,0 +1,95 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::ArrayVec;
+
+/// Serializes the elements as a sequence, like a slice.
+impl<T, const N: usize> Serialize for ArrayVec<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self)
+    }
+}
+
+/// Deserializes a sequence of at most `N` elements directly into the
+/// vector, failing if it is longer.
+///
+/// Overflow is reported with [`Error::invalid_length`]. The length is that
+/// of the sequence if the format provides it up front. Otherwise
+/// deserialization stops at the first element that does not fit, and the
+/// reported length is `N + 1`, the number of elements read so far.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::ArrayVec;
+///
+/// let v: ArrayVec<i32, 4> = serde_json::from_str("[1, 2, 3]").unwrap();
+/// assert_eq!(v, [1, 2, 3]);
+///
+/// let err = serde_json::from_str::<ArrayVec<i32, 2>>("[1, 2, 3]").unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "invalid length 3, expected a sequence of at most 2 elements at line 1 column 9",
+/// );
+/// ```
+impl<'de, T, const N: usize> Deserialize<'de> for ArrayVec<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ArrayVecVisitor(PhantomData))
+    }
+}
+
+struct ArrayVecVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVecVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ArrayVec<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if N == 1 {
+            f.write_str("a sequence of at most 1 element")
+        } else {
+            write!(f, "a sequence of at most {} elements", N)
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Formats that know the length up front can fail before reading any
+        // elements.
+        if let Some(len) = seq.size_hint() {
+            if len > N {
+                return Err(A::Error::invalid_length(len, &self));
+            }
+        }
+
+        let mut vec = ArrayVec::new();
+        while let Some(element) = seq.next_element()? {
+            if vec.try_push(element).is_err() {
+                // Reading the rest of the sequence to count it could be
+                // unbounded, so report the elements seen so far.
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+        }
+        Ok(vec)
+    }
+}
@@ -0,0 +1,995 @@
+//! A vector stored inline until it outgrows its inline capacity, and its
+//! iterators.
+
+use alloc::vec::{self, Vec};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut, RangeBounds},
+    ptr::{self, NonNull},
+    slice::{Iter, IterMut},
+};
+
+use crate::{range_to_indices, ArrayVec, CapacityError};
+
+/// A vector that stores up to `N` elements inline, in an [`ArrayVec`], and
+/// moves them to a [`Vec`] the first time it needs more room.
+///
+/// Operations that fit within the inline capacity never allocate, and work
+/// on the `ArrayVec` directly.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::SmallVec;
+///
+/// let mut v: SmallVec<i32, 2> = SmallVec::new();
+/// v.push(1);
+/// v.push(2);
+/// assert!(!v.spilled());
+///
+/// v.push(3);
+/// assert!(v.spilled());
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+pub struct SmallVec<T, const N: usize> {
+    repr: Repr<T, N>,
+}
+
+enum Repr<T, const N: usize> {
+    Inline(ArrayVec<T, N>),
+    Heap(Vec<T>),
+}
+
+/// Evaluates `$body` with `$v` bound to the inline or heap vector.
+macro_rules! delegate {
+    ($self:expr, $v:ident => $body:expr) => {
+        match $self {
+            Repr::Inline($v) => $body,
+            Repr::Heap($v) => $body,
+        }
+    };
+}
+
+/// Evaluates `$body` with `$it` bound to the inline or heap iterator.
+macro_rules! delegate_iter {
+    ($self:expr, $inner:ident, $it:ident => $body:expr) => {
+        match $self {
+            $inner::Inline($it) => $body,
+            $inner::Heap($it) => $body,
+        }
+    };
+}
+
+impl<T, const N: usize> SmallVec<T, N> {
+    // Constructors
+
+    /// Constructs a new, empty `SmallVec`. It does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// const EMPTY: SmallVec<i32, 4> = SmallVec::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            repr: Repr::Inline(ArrayVec::new()),
+        }
+    }
+
+    /// Constructs a new, empty `SmallVec` with room for at least `capacity`
+    /// elements. It only allocates if `capacity` is greater than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// assert!(!SmallVec::<i32, 4>::with_capacity(4).spilled());
+    /// assert!(SmallVec::<i32, 4>::with_capacity(5).spilled());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        if capacity > N {
+            Self {
+                repr: Repr::Heap(Vec::with_capacity(capacity)),
+            }
+        } else {
+            Self::new()
+        }
+    }
+
+    /// Returns the number of elements the vector can hold without
+    /// reallocating: `N` while inline.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(_) => N,
+            Repr::Heap(v) => v.capacity(),
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    pub fn len(&self) -> usize {
+        delegate!(&self.repr, v => v.len())
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    /// Appends an element to the back of the vector, moving to the heap if
+    /// the inline storage is full.
+    #[inline]
+    pub fn push(&mut self, element: T) {
+        match &mut self.repr {
+            Repr::Inline(v) => {
+                if let Err(err) = v.try_push(element) {
+                    self.spill(1).push(err.element());
+                }
+            }
+            Repr::Heap(v) => v.push(element),
+        }
+    }
+
+    /// Removes the last element from the vector and returns it, or [`None`]
+    /// if it is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        delegate!(&mut self.repr, v => v.pop())
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        delegate!(&mut self.repr, v => v.as_mut_ptr())
+    }
+
+    /// Extracts a mutable slice of the entire vector.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        delegate!(&mut self.repr, v => v.as_mut_slice())
+    }
+
+    /// Returns a raw pointer to the vector's buffer.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        delegate!(&self.repr, v => v.as_ptr())
+    }
+
+    /// Extracts a slice containing the entire vector.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        delegate!(&self.repr, v => v.as_slice())
+    }
+
+    /// Clears the vector, removing all values. The vector stays on the heap
+    /// if it has spilled.
+    #[inline]
+    pub fn clear(&mut self) {
+        delegate!(&mut self.repr, v => v.clear())
+    }
+
+    /// Removes consecutive repeated elements in the vector according to the
+    /// [`PartialEq`] trait implementation.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        delegate!(&mut self.repr, v => v.dedup())
+    }
+
+    /// Removes all but the first of consecutive elements in the vector
+    /// satisfying a given equality relation.
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        delegate!(&mut self.repr, v => v.dedup_by(same_bucket))
+    }
+
+    /// Removes all but the first of consecutive elements in the vector that
+    /// resolve to the same key.
+    #[inline]
+    pub fn dedup_by_key<F, K>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        delegate!(&mut self.repr, v => v.dedup_by_key(key))
+    }
+
+    /// Removes the specified range from the vector, returning the removed
+    /// elements as an iterator.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 4> = (1..=3).collect();
+    /// assert!(v.drain(1..).eq([2, 3]));
+    /// assert_eq!(v, [1]);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> Drain<'_, T, N>
+    where
+        R: RangeBounds<usize>,
+    {
+        let inner = match &mut self.repr {
+            Repr::Inline(v) => DrainInner::Inline(v.drain(range)),
+            Repr::Heap(v) => match range_to_indices(range, v.len()) {
+                Ok((start, end)) => DrainInner::Heap(v.drain(start..end)),
+                Err(_) => panic!("drain range out of bounds"),
+            },
+        };
+        Drain { inner }
+    }
+
+    /// Clones and appends all elements of `other`, moving to the heap if
+    /// they do not fit inline.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        match &mut self.repr {
+            Repr::Inline(v) => v.extend_from_slice(other),
+            Repr::Heap(v) => v.extend_from_slice(other),
+        }
+    }
+
+    /// Inserts an element at position `idx`, shifting all elements after it
+    /// to the right, and moving to the heap if the inline storage is full.
+    ///
+    /// Panics if `idx > len`.
+    pub fn insert(&mut self, idx: usize, element: T) {
+        if idx > self.len() {
+            panic!("insertion index out of bounds");
+        }
+
+        match &mut self.repr {
+            Repr::Inline(v) => {
+                if let Err(err) = v.try_insert(idx, element) {
+                    self.spill(1).insert(idx, err.element());
+                }
+            }
+            Repr::Heap(v) => v.insert(idx, element),
+        }
+    }
+
+    /// Converts the vector into an `ArrayVec`, or returns it unchanged if its
+    /// elements do not fit in one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 2> = (1..=3).collect();
+    /// v = v.into_inline().unwrap_err();
+    /// v.pop();
+    /// assert_eq!(v.into_inline().unwrap(), [1, 2]);
+    /// ```
+    pub fn into_inline(mut self) -> Result<ArrayVec<T, N>, Self> {
+        if self.shrink_to_inline().is_err() {
+            return Err(self);
+        }
+
+        match mem::replace(&mut self.repr, Repr::Inline(ArrayVec::new())) {
+            Repr::Inline(v) => Ok(v),
+            Repr::Heap(_) => unreachable!(),
+        }
+    }
+
+    /// Converts the vector into a `Vec`. This does not reallocate if the
+    /// vector has spilled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let v: SmallVec<i32, 2> = (1..=3).collect();
+    /// assert_eq!(v.into_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_vec(mut self) -> Vec<T> {
+        match mem::replace(&mut self.repr, Repr::Inline(ArrayVec::new())) {
+            Repr::Inline(v) => Self::move_to_vec(v, 0),
+            Repr::Heap(v) => v,
+        }
+    }
+
+    /// Removes and returns the element at position `idx`, shifting all
+    /// elements after it to the left.
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> T {
+        if idx >= self.len() {
+            panic!("removal index out of bounds");
+        }
+        delegate!(&mut self.repr, v => v.remove(idx))
+    }
+
+    /// Reserves room for at least `additional` more elements, moving to the
+    /// heap if they do not fit inline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 4> = SmallVec::new();
+    /// v.reserve(4);
+    /// assert!(!v.spilled());
+    /// v.reserve(5);
+    /// assert!(v.spilled());
+    /// assert!(v.capacity() >= 5);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.repr {
+            Repr::Inline(v) => {
+                if additional > N - v.len() {
+                    self.spill(additional);
+                }
+            }
+            Repr::Heap(v) => v.reserve(additional),
+        }
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// clones of `value`, and moving to the heap if it does not fit inline.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 2> = SmallVec::new();
+    /// v.resize(3, 7);
+    /// assert!(v.spilled());
+    /// assert_eq!(v, [7, 7, 7]);
+    /// v.resize(1, 0);
+    /// assert_eq!(v, [7]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.reserve(new_len.saturating_sub(self.len()));
+        delegate!(&mut self.repr, v => v.resize(new_len, value))
+    }
+
+    /// Resizes the vector to `new_len`, either truncating it or appending
+    /// values returned by `f`, and moving to the heap if it does not fit
+    /// inline.
+    pub fn resize_with<F>(&mut self, new_len: usize, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.reserve(new_len.saturating_sub(self.len()));
+        delegate!(&mut self.repr, v => v.resize_with(new_len, f))
+    }
+
+    /// Retains only the elements specified by the predicate.
+    #[inline]
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        match &mut self.repr {
+            Repr::Inline(v) => v.retain(|element| f(element)),
+            Repr::Heap(v) => v.retain(f),
+        }
+    }
+
+    /// Retains only the elements specified by the predicate, which may
+    /// modify them.
+    #[inline]
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        delegate!(&mut self.repr, v => v.retain_mut(f))
+    }
+
+    /// Moves the elements back inline if they fit, freeing the heap
+    /// allocation. Fails, leaving the vector unchanged, if they do not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 2> = (1..=3).collect();
+    /// assert!(v.shrink_to_inline().is_err());
+    ///
+    /// v.pop();
+    /// assert!(v.shrink_to_inline().is_ok());
+    /// assert!(!v.spilled());
+    /// assert_eq!(v, [1, 2]);
+    /// ```
+    pub fn shrink_to_inline(&mut self) -> Result<(), CapacityError> {
+        let heap = match &mut self.repr {
+            Repr::Inline(_) => return Ok(()),
+            Repr::Heap(v) if v.len() > N => return Err(CapacityError::new(())),
+            Repr::Heap(v) => v,
+        };
+
+        let mut inline = ArrayVec::new();
+        unsafe {
+            ptr::copy_nonoverlapping(heap.as_ptr(), inline.as_mut_ptr(), heap.len());
+            inline.set_len(heap.len());
+            heap.set_len(0);
+        }
+        self.repr = Repr::Inline(inline);
+        Ok(())
+    }
+
+    /// Replaces the specified range with the elements of `replace_with`,
+    /// returning the removed elements as an iterator. The vector moves to the
+    /// heap if the replacement does not fit inline.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SmallVec;
+    ///
+    /// let mut v: SmallVec<i32, 4> = (1..=3).collect();
+    /// assert!(v.splice(1..2, [7, 8, 9, 10]).eq([2]));
+    /// assert!(v.spilled());
+    /// assert_eq!(v, [1, 7, 8, 9, 10, 3]);
+    /// ```
+    pub fn splice<I, R>(&mut self, range: R, replace_with: I) -> Splice<'_, I::IntoIter, N>
+    where
+        I: IntoIterator<Item = T>,
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = match range_to_indices(range, self.len()) {
+            Ok(indices) => indices,
+            Err(_) => panic!("drain range out of bounds"),
+        };
+        let tail_len = self.len() - end;
+
+        // Derive both the borrow of the storage and the pointer used to spill
+        // from the same raw pointer.
+        let this = NonNull::from(self);
+        let inner = match unsafe { &mut (*this.as_ptr()).repr } {
+            Repr::Inline(v) => SpliceInner::Inline {
+                splice: ManuallyDrop::new(v.splice(start..end, replace_with)),
+                vec: this,
+                tail_len,
+                _marker: PhantomData,
+            },
+            Repr::Heap(v) => SpliceInner::Heap(v.splice(start..end, replace_with)),
+        };
+        Splice { inner }
+    }
+
+    /// Splits the vector in two at `at`, returning the elements from `at`
+    /// onwards.
+    ///
+    /// The returned vector is inline if `self` is, and on the heap otherwise.
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len() {
+            panic!("split index out of bounds");
+        }
+
+        match &mut self.repr {
+            Repr::Inline(v) => Self::from(v.split_off(at)),
+            Repr::Heap(v) => Self::from(v.split_off(at)),
+        }
+    }
+
+    /// Removes and returns the element at position `idx`, replacing it with
+    /// the last element.
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn swap_remove(&mut self, idx: usize) -> T {
+        if idx >= self.len() {
+            panic!("swap_remove index out of bounds");
+        }
+        delegate!(&mut self.repr, v => v.swap_remove(idx))
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping
+    /// the rest.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        delegate!(&mut self.repr, v => v.truncate(len))
+    }
+
+    // Private helpers
+
+    /// Moves the elements of `inline` into a new `Vec` with room for at
+    /// least `additional` more.
+    fn move_to_vec(mut inline: ArrayVec<T, N>, additional: usize) -> Vec<T> {
+        let len = inline.len();
+        let mut vec = Vec::with_capacity(len + additional);
+        unsafe {
+            ptr::copy_nonoverlapping(inline.as_ptr(), vec.as_mut_ptr(), len);
+            inline.set_len(0);
+            vec.set_len(len);
+        }
+        vec
+    }
+
+    /// Moves the elements to the heap, with room for at least `additional`
+    /// more, and returns the new `Vec`.
+    fn spill(&mut self, additional: usize) -> &mut Vec<T> {
+        if let Repr::Inline(v) = &mut self.repr {
+            let inline = mem::take(v);
+            // Grow geometrically, as `Vec` would.
+            let additional = additional.max(N);
+            self.repr = Repr::Heap(Self::move_to_vec(inline, additional));
+        }
+
+        match &mut self.repr {
+            Repr::Heap(v) => v,
+            Repr::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for SmallVec<T, N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Clone for SmallVec<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline(v) => Repr::Inline(v.clone()),
+            Repr::Heap(v) => Repr::Heap(v.clone()),
+        };
+        Self { repr }
+    }
+}
+
+impl<T, const N: usize> Default for SmallVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for SmallVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for SmallVec<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Eq for SmallVec<T, N> where T: Eq {}
+
+impl<T, const N: usize> Extend<T> for SmallVec<T, N> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+/// Keeps the elements inline, without copying them.
+impl<T, const N: usize> From<ArrayVec<T, N>> for SmallVec<T, N> {
+    #[inline]
+    fn from(vec: ArrayVec<T, N>) -> Self {
+        Self {
+            repr: Repr::Inline(vec),
+        }
+    }
+}
+
+/// Keeps the elements on the heap, without copying them. Use
+/// [`SmallVec::shrink_to_inline`] to move them inline.
+impl<T, const N: usize> From<Vec<T>> for SmallVec<T, N> {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self {
+            repr: Repr::Heap(vec),
+        }
+    }
+}
+
+impl<T, const N: usize> From<SmallVec<T, N>> for Vec<T> {
+    #[inline]
+    fn from(vec: SmallVec<T, N>) -> Self {
+        vec.into_vec()
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for SmallVec<T, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, const N: usize> Hash for SmallVec<T, N>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SmallVec<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(mut self) -> IntoIter<T, N> {
+        let inner = match mem::replace(&mut self.repr, Repr::Inline(ArrayVec::new())) {
+            Repr::Inline(v) => IntoIterInner::Inline(v.into_iter()),
+            Repr::Heap(v) => IntoIterInner::Heap(v.into_iter()),
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SmallVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut SmallVec<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> Ord for SmallVec<T, N>
+where
+    T: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+macro_rules! __impl_slice_eq1 {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, $($vars)* const N: usize> PartialEq<$rhs> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool { self[..] == other[..] }
+        }
+    }
+}
+
+__impl_slice_eq1! { [const O: usize,] SmallVec<T, N>, SmallVec<U, O> }
+__impl_slice_eq1! { [] SmallVec<T, N>, &[U] }
+__impl_slice_eq1! { [] SmallVec<T, N>, &mut [U] }
+__impl_slice_eq1! { [] SmallVec<T, N>, [U] }
+__impl_slice_eq1! { [] SmallVec<T, N>, Vec<U> }
+__impl_slice_eq1! { [const O: usize,] SmallVec<T, N>, [U; O] }
+__impl_slice_eq1! { [const O: usize,] SmallVec<T, N>, &[U; O] }
+
+impl<T, const N: usize> PartialOrd for SmallVec<T, N>
+where
+    T: PartialOrd,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for SmallVec<T, N>
+where
+    T: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+/// A draining iterator for a [`SmallVec`].
+///
+/// This is created by [`SmallVec::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    inner: DrainInner<'a, T, N>,
+}
+
+enum DrainInner<'a, T, const N: usize> {
+    Inline(crate::Drain<'a, T, N>),
+    Heap(vec::Drain<'a, T>),
+}
+
+impl<T, const N: usize> Drain<'_, T, N> {
+    /// Returns the remaining items of this iterator as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        delegate_iter!(&self.inner, DrainInner, d => d.as_slice())
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Drain<'_, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        delegate_iter!(&mut self.inner, DrainInner, d => d.next_back())
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for Drain<'_, T, N> {}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        delegate_iter!(&mut self.inner, DrainInner, d => d.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        delegate_iter!(&self.inner, DrainInner, d => d.size_hint())
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for Drain<'_, T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SmallVecDrain")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+/// An iterator that moves out of a [`SmallVec`].
+///
+/// This is created by the `into_iter` method on `SmallVec` (provided by the
+/// [`IntoIterator`] trait).
+pub struct IntoIter<T, const N: usize> {
+    inner: IntoIterInner<T, N>,
+}
+
+enum IntoIterInner<T, const N: usize> {
+    Inline(crate::IntoIter<T, N>),
+    Heap(vec::IntoIter<T>),
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Returns the remaining items of this iterator as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        delegate_iter!(&self.inner, IntoIterInner, it => it.as_slice())
+    }
+
+    /// Returns the remaining items of this iterator as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        delegate_iter!(&mut self.inner, IntoIterInner, it => it.as_mut_slice())
+    }
+}
+
+impl<T, const N: usize> Clone for IntoIter<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        let inner = match &self.inner {
+            IntoIterInner::Inline(it) => IntoIterInner::Inline(it.clone()),
+            IntoIterInner::Heap(it) => IntoIterInner::Heap(it.clone()),
+        };
+        Self { inner }
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        delegate_iter!(&mut self.inner, IntoIterInner, it => it.next_back())
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        delegate_iter!(&mut self.inner, IntoIterInner, it => it.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        delegate_iter!(&self.inner, IntoIterInner, it => it.size_hint())
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for IntoIter<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SmallVecIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
+
+/// A splicing iterator for a [`SmallVec`].
+///
+/// This is created by [`SmallVec::splice`]. The replacement elements are
+/// inserted when it is dropped, moving the vector to the heap if they do not
+/// fit inline.
+pub struct Splice<'a, I, const N: usize>
+where
+    I: Iterator + 'a,
+{
+    inner: SpliceInner<'a, I, N>,
+}
+
+enum SpliceInner<'a, I, const N: usize>
+where
+    I: Iterator + 'a,
+{
+    Inline {
+        splice: ManuallyDrop<crate::Splice<'a, I, N>>,
+        /// Will be used to spill the vector if the replacement overflows
+        vec: NonNull<SmallVec<I::Item, N>>,
+        /// Number of elements after the replaced range
+        tail_len: usize,
+        _marker: PhantomData<&'a mut SmallVec<I::Item, N>>,
+    },
+    Heap(vec::Splice<'a, I>),
+}
+
+impl<I, const N: usize> DoubleEndedIterator for Splice<'_, I, N>
+where
+    I: Iterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            SpliceInner::Inline { splice, .. } => splice.next_back(),
+            SpliceInner::Heap(splice) => splice.next_back(),
+        }
+    }
+}
+
+impl<I, const N: usize> Drop for Splice<'_, I, N>
+where
+    I: Iterator,
+{
+    fn drop(&mut self) {
+        if let SpliceInner::Inline {
+            splice,
+            vec,
+            tail_len,
+            ..
+        } = &mut self.inner
+        {
+            let splice = unsafe { ManuallyDrop::take(splice) };
+            if let Err(err) = splice.try_finish() {
+                // The inline vector is full, with the tail at its end. The
+                // rest of the replacement goes just before the tail.
+                let vec = unsafe { vec.as_mut() };
+                let at = vec.len() - *tail_len;
+                vec.spill(0).splice(at..at, err.element());
+            }
+        }
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for Splice<'_, I, N> where I: Iterator {}
+
+impl<I, const N: usize> Iterator for Splice<'_, I, N>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            SpliceInner::Inline { splice, .. } => splice.next(),
+            SpliceInner::Heap(splice) => splice.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            SpliceInner::Inline { splice, .. } => splice.size_hint(),
+            SpliceInner::Heap(splice) => splice.size_hint(),
+        }
+    }
+}
+
+impl<I, const N: usize> fmt::Debug for Splice<'_, I, N>
+where
+    I: Iterator + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            SpliceInner::Inline { splice, .. } => fmt::Debug::fmt(&**splice, f),
+            SpliceInner::Heap(splice) => fmt::Debug::fmt(splice, f),
+        }
+    }
+}
@@ -0,0 +1,583 @@
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    ops::{Bound, Deref, RangeBounds},
+};
+
+use crate::{ArrayVec, CapacityError, IntoIter};
+
+/// A fixed-capacity vector that keeps its elements in ascending order.
+///
+/// Equal elements are allowed and keep their insertion order. Lookups use
+/// binary search, and the elements can be read as a sorted slice but not
+/// modified in place.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::SortedArrayVec;
+///
+/// let mut deadlines: SortedArrayVec<u32, 4> = SortedArrayVec::new();
+/// deadlines.insert(30);
+/// deadlines.insert(10);
+/// deadlines.insert(20);
+/// assert_eq!(deadlines, [10, 20, 30]);
+/// assert_eq!(deadlines.range(15..), &[20, 30]);
+/// assert_eq!(deadlines.first(), Some(&10));
+/// ```
+#[derive(Clone, Eq, Hash, Ord, PartialOrd)]
+pub struct SortedArrayVec<T, const N: usize> {
+    vec: ArrayVec<T, N>,
+}
+
+impl<T, const N: usize> SortedArrayVec<T, N> {
+    // Constructors
+
+    /// Constructs a new, empty `SortedArrayVec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArrayVec;
+    ///
+    /// const EMPTY: SortedArrayVec<i32, 4> = SortedArrayVec::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            vec: ArrayVec::new(),
+        }
+    }
+
+    /// Constructs a `SortedArrayVec` by sorting the elements of `vec`.
+    ///
+    /// The sort is not stable, so equal elements may be reordered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let v = SortedArrayVec::from_array_vec(ArrayVec::from_array([3, 1, 2]));
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    pub fn from_array_vec(mut vec: ArrayVec<T, N>) -> Self
+    where
+        T: Ord,
+    {
+        vec.sort_unstable();
+        Self { vec }
+    }
+
+    /// Wraps `vec` without checking that it is sorted.
+    ///
+    /// If the elements are not in ascending order, lookups and insertions
+    /// give unspecified, but memory-safe, results.
+    #[inline]
+    pub const fn from_sorted_unchecked(vec: ArrayVec<T, N>) -> Self {
+        Self { vec }
+    }
+
+    // Methods
+
+    /// Returns the elements as a sorted slice.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Returns the number of elements the vector can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Removes all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    /// Returns `true` if the vector holds an element equal to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArrayVec;
+    ///
+    /// let mut v: SortedArrayVec<&str, 4> = SortedArrayVec::new();
+    /// v.insert("b");
+    /// assert!(v.contains("b"));
+    /// assert!(!v.contains("a"));
+    /// ```
+    #[inline]
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q>,
+    {
+        self.position(value).is_some()
+    }
+
+    /// Inserts `value` after any equal elements, returning its index.
+    ///
+    /// Panics if the vector is full. See [`SortedArrayVec::try_insert`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArrayVec;
+    ///
+    /// let mut v: SortedArrayVec<i32, 4> = SortedArrayVec::new();
+    /// assert_eq!(v.insert(5), 0);
+    /// assert_eq!(v.insert(1), 0);
+    /// assert_eq!(v.insert(5), 2);
+    /// assert_eq!(v, [1, 5, 5]);
+    /// ```
+    pub fn insert(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        match self.try_insert(value) {
+            Ok(idx) => idx,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Returns a vector holding the elements found in both vectors.
+    ///
+    /// Panics if they do not fit in `K` elements. See
+    /// [`SortedArrayVec::try_intersection`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let a = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 2, 3]));
+    /// let b = SortedArrayVec::from_array_vec(ArrayVec::from_array([2, 3, 4]));
+    /// let common: SortedArrayVec<i32, 3> = a.intersection(&b);
+    /// assert_eq!(common, [2, 3]);
+    /// ```
+    pub fn intersection<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> SortedArrayVec<T, K>
+    where
+        T: Clone + Ord,
+    {
+        match self.try_intersection(other) {
+            Ok(common) => common,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Returns the elements as a plain vector.
+    #[inline]
+    pub fn into_inner(self) -> ArrayVec<T, N> {
+        self.vec
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns `true` if the vector is at full capacity.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.vec.len() == N
+    }
+
+    /// Returns the number of elements.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns a vector holding the elements of both vectors, keeping all
+    /// duplicates.
+    ///
+    /// Panics if they do not fit in `K` elements. See
+    /// [`SortedArrayVec::try_merge`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let a = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 3]));
+    /// let b = SortedArrayVec::from_array_vec(ArrayVec::from_array([2, 3]));
+    /// let merged: SortedArrayVec<i32, 4> = a.merge(&b);
+    /// assert_eq!(merged, [1, 2, 3, 3]);
+    /// ```
+    pub fn merge<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> SortedArrayVec<T, K>
+    where
+        T: Clone + Ord,
+    {
+        match self.try_merge(other) {
+            Ok(merged) => merged,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Removes and returns the last, and so largest, element.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.vec.pop()
+    }
+
+    /// Returns the elements that lie within `range`.
+    ///
+    /// The slice is empty if the range is empty or reversed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let v = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 2, 2, 3, 5]));
+    /// assert_eq!(v.range(2..=3), &[2, 2, 3]);
+    /// assert_eq!(v.range(..2), &[1]);
+    /// assert_eq!(v.range(4..), &[5]);
+    /// assert!(v.range(3..2).is_empty());
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+    where
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+        T: Borrow<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.partition_point(|e| e.borrow() < start),
+            Bound::Excluded(start) => self.partition_point(|e| e.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.partition_point(|e| e.borrow() <= end),
+            Bound::Excluded(end) => self.partition_point(|e| e.borrow() < end),
+            Bound::Unbounded => self.len(),
+        };
+        &self.vec[start..end.max(start)]
+    }
+
+    /// Removes and returns the element at position `idx`.
+    ///
+    /// Panics if `idx` is out of bounds.
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> T {
+        self.vec.remove(idx)
+    }
+
+    /// Removes and returns an element equal to `value`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let mut v = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 2, 3]));
+    /// assert_eq!(v.remove_value(&2), Some(2));
+    /// assert_eq!(v.remove_value(&2), None);
+    /// assert_eq!(v, [1, 3]);
+    /// ```
+    pub fn remove_value<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q>,
+    {
+        let idx = self.position(value)?;
+        Some(self.vec.remove(idx))
+    }
+
+    /// Retains only the elements specified by the predicate.
+    #[inline]
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.vec.retain(f);
+    }
+
+    /// Shortens the vector to `len` elements, dropping the largest ones.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    /// Returns a vector holding the elements found in either vector.
+    ///
+    /// Panics if they do not fit in `K` elements. See
+    /// [`SortedArrayVec::try_union`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let a = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 2, 3]));
+    /// let b = SortedArrayVec::from_array_vec(ArrayVec::from_array([2, 3, 4]));
+    /// let all: SortedArrayVec<i32, 4> = a.union(&b);
+    /// assert_eq!(all, [1, 2, 3, 4]);
+    /// ```
+    pub fn union<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> SortedArrayVec<T, K>
+    where
+        T: Clone + Ord,
+    {
+        match self.try_union(other) {
+            Ok(all) => all,
+            Err(_) => panic!("capacity overflow"),
+        }
+    }
+
+    /// Inserts `value` after any equal elements and returns its index, or
+    /// returns `value` in a [`CapacityError`] if the vector is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::SortedArrayVec;
+    ///
+    /// let mut v: SortedArrayVec<i32, 1> = SortedArrayVec::new();
+    /// assert_eq!(v.try_insert(2), Ok(0));
+    /// assert_eq!(v.try_insert(1).unwrap_err().element(), 1);
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<usize, CapacityError<T>>
+    where
+        T: Ord,
+    {
+        let idx = self.partition_point(|e| *e <= value);
+        self.vec.try_insert(idx, value)?;
+        Ok(idx)
+    }
+
+    /// Returns a vector holding the elements found in both vectors, or fails
+    /// if they do not fit in `K` elements.
+    ///
+    /// An element that appears several times in both vectors is kept as many
+    /// times as it appears in the one that holds it fewer times.
+    pub fn try_intersection<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> Result<SortedArrayVec<T, K>, CapacityError>
+    where
+        T: Clone + Ord,
+    {
+        self.combine(other, SetOp::Intersection)
+    }
+
+    /// Returns a vector holding the elements of both vectors, keeping all
+    /// duplicates, or fails if they do not fit in `K` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::{ArrayVec, SortedArrayVec};
+    ///
+    /// let a = SortedArrayVec::from_array_vec(ArrayVec::from_array([1, 3]));
+    /// let b = SortedArrayVec::from_array_vec(ArrayVec::from_array([2, 3]));
+    /// assert!(a.try_merge::<2, 3>(&b).is_err());
+    /// ```
+    pub fn try_merge<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> Result<SortedArrayVec<T, K>, CapacityError>
+    where
+        T: Clone + Ord,
+    {
+        self.combine(other, SetOp::Merge)
+    }
+
+    /// Returns a vector holding the elements found in either vector, or
+    /// fails if they do not fit in `K` elements.
+    ///
+    /// An element that appears several times is kept as many times as it
+    /// appears in the vector that holds it more times.
+    pub fn try_union<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+    ) -> Result<SortedArrayVec<T, K>, CapacityError>
+    where
+        T: Clone + Ord,
+    {
+        self.combine(other, SetOp::Union)
+    }
+
+    /// Walks both vectors in order, cloning the elements kept by `op`.
+    fn combine<const M: usize, const K: usize>(
+        &self,
+        other: &SortedArrayVec<T, M>,
+        op: SetOp,
+    ) -> Result<SortedArrayVec<T, K>, CapacityError>
+    where
+        T: Clone + Ord,
+    {
+        let mut out = ArrayVec::new();
+        let mut push = |element: &T| {
+            out.try_push(element.clone())
+                .map_err(CapacityError::simplify)
+        };
+        let keep_unmatched = !matches!(op, SetOp::Intersection);
+
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+        loop {
+            match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    Ordering::Less => {
+                        if keep_unmatched {
+                            push(x)?;
+                        }
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        if keep_unmatched {
+                            push(y)?;
+                        }
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        push(x)?;
+                        if let SetOp::Merge = op {
+                            push(y)?;
+                        }
+                        a.next();
+                        b.next();
+                    }
+                },
+                (Some(x), None) if keep_unmatched => {
+                    push(x)?;
+                    a.next();
+                }
+                (None, Some(y)) if keep_unmatched => {
+                    push(y)?;
+                    b.next();
+                }
+                _ => break,
+            }
+        }
+
+        Ok(SortedArrayVec { vec: out })
+    }
+
+    fn position<Q>(&self, value: &Q) -> Option<usize>
+    where
+        Q: Ord + ?Sized,
+        T: Borrow<Q>,
+    {
+        self.binary_search_by(|e| e.borrow().cmp(value)).ok()
+    }
+}
+
+enum SetOp {
+    Intersection,
+    Merge,
+    Union,
+}
+
+impl<T, const N: usize> AsRef<[T]> for SortedArrayVec<T, N> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, const N: usize> Default for SortedArrayVec<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for SortedArrayVec<T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> From<ArrayVec<T, N>> for SortedArrayVec<T, N>
+where
+    T: Ord,
+{
+    #[inline]
+    fn from(vec: ArrayVec<T, N>) -> Self {
+        Self::from_array_vec(vec)
+    }
+}
+
+impl<T, const N: usize> From<SortedArrayVec<T, N>> for ArrayVec<T, N> {
+    #[inline]
+    fn from(vec: SortedArrayVec<T, N>) -> Self {
+        vec.vec
+    }
+}
+
+impl<T, const N: usize> IntoIterator for SortedArrayVec<T, N> {
+    type IntoIter = IntoIter<T, N>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a SortedArrayVec<T, N> {
+    type IntoIter = core::slice::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<SortedArrayVec<U, M>> for SortedArrayVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &SortedArrayVec<U, M>) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<[U; M]> for SortedArrayVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U; M]) -> bool {
+        self[..] == other[..]
+    }
+}
+
+impl<T, U, const N: usize> PartialEq<[U]> for SortedArrayVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    #[inline]
+    fn eq(&self, other: &[U]) -> bool {
+        self[..] == *other
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for SortedArrayVec<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
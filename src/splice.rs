@@ -1,6 +1,6 @@
-use core::{ptr, slice};
+use core::{iter, mem::ManuallyDrop, ptr, slice};
 
-use crate::Drain;
+use crate::{CapacityError, Drain, Remaining};
 
 #[derive(Debug)]
 pub struct Splice<'a, I, const N: usize>
@@ -11,6 +11,41 @@ where
     pub(crate) replace_with: I,
 }
 
+impl<I, const N: usize> Splice<'_, I, N>
+where
+    I: Iterator,
+{
+    /// Drops the rest of the removed range and inserts the replacement
+    /// elements, failing if they do not all fit.
+    ///
+    /// The elements that fit are kept. On overflow, an iterator over the
+    /// elements that did not fit is returned; its first element is the one
+    /// taken from the replacement iterator to detect the overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stack_based_vec::ArrayVec;
+    ///
+    /// let mut v: ArrayVec<i32, 4> = ArrayVec::from_partial_array([1, 2, 3]);
+    /// let rest = v.splice(1..2, 7..10).try_finish().unwrap_err().element();
+    /// assert_eq!(v.as_slice(), &[1, 7, 8, 3]);
+    /// assert!(rest.eq(9..10));
+    /// ```
+    pub fn try_finish(self) -> Result<(), CapacityError<Remaining<I::Item, I>>> {
+        // Move both fields out, so that they are dropped as usual if
+        // `replace_with` panics; dropping `drain` restores the vector.
+        let this = ManuallyDrop::new(self);
+        let mut drain = unsafe { ptr::read(&this.drain) };
+        let mut replace_with = unsafe { ptr::read(&this.replace_with) };
+
+        match unsafe { drain.splice_in(&mut replace_with) } {
+            Some(element) => Err(CapacityError::new(iter::once(element).chain(replace_with))),
+            None => Ok(()),
+        }
+    }
+}
+
 impl<I, const N: usize> DoubleEndedIterator for Splice<'_, I, N>
 where
     I: Iterator,
@@ -20,49 +55,16 @@ where
     }
 }
 
+/// Replacement elements that do not fit in the vector are dropped, and the
+/// replacement iterator is not advanced past the first of them. Use
+/// [`Splice::try_finish`] to detect overflow instead.
 impl<I, const N: usize> Drop for Splice<'_, I, N>
 where
     I: Iterator,
 {
     fn drop(&mut self) {
-        self.drain.by_ref().for_each(drop);
-
-        unsafe {
-            if self.drain.tail_len == 0 {
-                self.drain.vec.as_mut().extend(self.replace_with.by_ref());
-                return;
-            }
-
-            // First fill the range left by drain().
-            if !self.drain.fill(&mut self.replace_with) {
-                return;
-            }
-
-            // There may be more elements. Use the lower bound as an estimate.
-            // FIXME: Is the upper bound a better guess? Or something else?
-            let (lower_bound, _upper_bound) = self.replace_with.size_hint();
-            if lower_bound > 0 {
-                self.drain.move_tail(lower_bound);
-                if !self.drain.fill(&mut self.replace_with) {
-                    return;
-                }
-            }
-
-            // Collect any remaining elements.
-            // This is a zero-length vector which does not allocate if `lower_bound` was exact.
-            let mut collected = self
-                .replace_with
-                .by_ref()
-                .collect::<Vec<I::Item>>()
-                .into_iter();
-            // Now we have an exact count.
-            if collected.len() > 0 {
-                self.drain.move_tail(collected.len());
-                let filled = self.drain.fill(&mut collected);
-                debug_assert!(filled);
-                debug_assert_eq!(collected.len(), 0);
-            }
-        }
+        // Never panic here: `Splice` may be dropped during unwinding.
+        drop(unsafe { self.drain.splice_in(&mut self.replace_with) });
         // Let `Drain::drop` move the tail back if necessary and restore `vec.len`.
     }
 }
@@ -84,8 +86,41 @@ where
     }
 }
 
-/// Private helper methods for `Splice::drop`
+/// Private helper methods for `Splice::drop` and `Splice::try_finish`
 impl<T, const N: usize> Drain<'_, T, N> {
+    /// Drops the rest of the drained range and fills the vector from
+    /// `replace_with`, up to its capacity.
+    /// Returns the first element that did not fit, if any.
+    unsafe fn splice_in<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> Option<T> {
+        self.by_ref().for_each(drop);
+
+        // First fill the range left by drain().
+        if !self.fill(replace_with) {
+            return None;
+        }
+
+        // There may be more elements. Use the lower bound as an estimate.
+        let (lower_bound, _upper_bound) = replace_with.size_hint();
+        let lower_bound = lower_bound.min(self.spare_capacity());
+        if lower_bound > 0 {
+            self.move_tail(lower_bound);
+            if !self.fill(replace_with) {
+                return None;
+            }
+        }
+
+        // Make room for as many elements as still fit.
+        let additional = self.spare_capacity();
+        if additional > 0 {
+            self.move_tail(additional);
+            if !self.fill(replace_with) {
+                return None;
+            }
+        }
+
+        replace_with.next()
+    }
+
     /// The range from `self.vec.len` to `self.tail_start` contains elements
     /// that have been moved out.
     /// Fill that range as much as possible with new elements from the `replace_with` iterator.
@@ -108,7 +143,14 @@ impl<T, const N: usize> Drain<'_, T, N> {
         true
     }
 
+    /// Number of slots between the end of the tail and the end of the buffer.
+    fn spare_capacity(&self) -> usize {
+        N - self.tail_start - self.tail_len
+    }
+
     /// Makes room for inserting more elements before the tail.
+    ///
+    /// `additional` must not exceed `self.spare_capacity()`.
     unsafe fn move_tail(&mut self, additional: usize) {
         let vec = self.vec.as_mut();
 
@@ -0,0 +1,228 @@
+//! A collector that keeps the greatest items of a stream.
+
+use core::{cmp::Ordering, fmt, iter::FromIterator};
+
+use crate::{
+    array_binary_heap::{Compare, Max},
+    ArrayBinaryHeap, ArrayVec,
+};
+
+/// Orders items by the key returned by a function, for [`TopK::by_key`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ByKey<F>(F);
+
+impl<T, K, F> Compare<T> for ByKey<F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0(a).cmp(&self.0(b))
+    }
+}
+
+/// Keeps the `N` greatest items pushed into it.
+///
+/// The items are stored as a binary heap whose top is the smallest kept
+/// item, so each push takes `O(log N)` time. When items compare equal, the
+/// ones pushed first are kept.
+///
+/// # Example
+///
+/// ```rust
+/// use stack_based_vec::TopK;
+///
+/// let mut longest = TopK::<_, 2, _>::by_key(|s: &&str| s.len());
+/// longest.extend(["a", "ccc", "bb", "dddd"].iter().copied());
+/// assert_eq!(longest.into_sorted_vec(), ["dddd", "ccc"]);
+/// ```
+#[derive(Clone)]
+pub struct TopK<T, const N: usize, C = Max> {
+    heap: ArrayBinaryHeap<T, N, Reversed<C>>,
+}
+
+impl<T, const N: usize> TopK<T, N>
+where
+    T: Ord,
+{
+    /// Constructs a new, empty collector ordered by `T: Ord`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_comparator(Max)
+    }
+}
+
+impl<T, const N: usize, F> TopK<T, N, ByKey<F>> {
+    /// Constructs a new, empty collector ordered by the key that `f` returns.
+    #[inline]
+    pub const fn by_key<K>(f: F) -> Self
+    where
+        F: Fn(&T) -> K,
+        K: Ord,
+    {
+        Self::with_comparator(ByKey(f))
+    }
+}
+
+impl<T, const N: usize, C> TopK<T, N, C>
+where
+    C: Compare<T>,
+{
+    // Constructors
+
+    /// Constructs a new, empty collector ordered by `cmp`.
+    #[inline]
+    pub const fn with_comparator(cmp: C) -> Self {
+        Self {
+            heap: ArrayBinaryHeap::with_comparator(Reversed(cmp)),
+        }
+    }
+
+    // Methods
+
+    /// Returns the kept items in heap order, which is unspecified.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        self.heap.as_slice()
+    }
+
+    /// Returns the number of items that are kept.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the kept items sorted from the greatest to the smallest.
+    #[inline]
+    pub fn into_sorted_vec(self) -> ArrayVec<T, N> {
+        self.heap.into_sorted_vec()
+    }
+
+    /// Returns the kept items in heap order, which is unspecified.
+    #[inline]
+    pub fn into_vec(self) -> ArrayVec<T, N> {
+        self.heap.into_vec()
+    }
+
+    /// Returns `true` if nothing has been kept yet.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns `true` if `N` items are kept, so that later items must be
+    /// greater than [`TopK::min`] to be kept.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.heap.is_full()
+    }
+
+    /// Returns the number of kept items.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns the smallest kept item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::TopK;
+    ///
+    /// let top: TopK<u32, 3> = [4, 9, 1, 7, 3].iter().copied().collect();
+    /// assert_eq!(top.min(), Some(&4));
+    /// ```
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek()
+    }
+
+    /// Offers `item` to the collector, returning the item that is no longer
+    /// kept, if any. This is either `item` itself or the previous
+    /// [`TopK::min`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stack_based_vec::TopK;
+    ///
+    /// let mut top: TopK<u32, 2> = TopK::new();
+    /// assert_eq!(top.push(5), None);
+    /// assert_eq!(top.push(1), None);
+    /// assert_eq!(top.push(3), Some(1));
+    /// assert_eq!(top.push(2), Some(2));
+    /// ```
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if self.heap.is_full() {
+            Some(self.heap.push_pop(item))
+        } else {
+            self.heap.push(item);
+            None
+        }
+    }
+}
+
+impl<T, const N: usize, C> Default for TopK<T, N, C>
+where
+    C: Compare<T> + Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_comparator(C::default())
+    }
+}
+
+impl<T, const N: usize, C> Extend<T> for TopK<T, N, C>
+where
+    C: Compare<T>,
+{
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().for_each(|item| {
+            let _ = self.push(item);
+        });
+    }
+}
+
+impl<T, const N: usize, C> FromIterator<T> for TopK<T, N, C>
+where
+    C: Compare<T> + Default,
+{
+    #[inline]
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut top = Self::with_comparator(C::default());
+        top.extend(iter);
+        top
+    }
+}
+
+impl<T, const N: usize, C> fmt::Debug for TopK<T, N, C>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.heap.fmt(f)
+    }
+}
+
+/// Puts the smallest item according to `C` on top of the heap.
+#[derive(Clone, Copy, Debug, Default)]
+struct Reversed<C>(C);
+
+impl<T, C> Compare<T> for Reversed<C>
+where
+    C: Compare<T>,
+{
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
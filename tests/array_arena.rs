@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use stack_based_vec::{ArrayArena, Handle};
+
+#[test]
+fn stale_handles_are_rejected() {
+    let mut arena: ArrayArena<char, 2> = ArrayArena::new();
+    let a = arena.insert('a').unwrap();
+    let b = arena.insert('b').unwrap();
+    assert_eq!(arena.insert('c'), Err('c'));
+
+    assert_eq!(arena.remove(a), Some('a'));
+    let c = arena.insert('c').unwrap();
+    assert_eq!((c.index(), c.generation()), (a.index(), a.generation() + 1));
+    assert_eq!(arena.get(a), None);
+    assert_eq!(arena.get_mut(a), None);
+    assert_eq!(arena.remove(a), None);
+    assert!(arena.get2_mut(a, b).is_none());
+    assert_eq!(arena[c], 'c');
+    assert_eq!(arena.len(), 2);
+}
+
+#[test]
+fn forged_handles_are_rejected() {
+    let mut arena: ArrayArena<u8, 2> = ArrayArena::new();
+    let a = arena.insert(1).unwrap();
+    assert_eq!(arena.get(Handle::new(a.index(), a.generation() + 1)), None);
+    assert_eq!(arena.get(Handle::new(1, 0)), None);
+    assert_eq!(arena.get(Handle::new(7, 0)), None);
+}
+
+#[test]
+#[should_panic(expected = "stale handle")]
+fn index_stale() {
+    let mut arena: ArrayArena<u8, 2> = ArrayArena::new();
+    let a = arena.insert(1).unwrap();
+    arena.clear();
+    let _ = arena[a];
+}
+
+#[test]
+fn iteration_and_drain() {
+    let mut arena: ArrayArena<u32, 4> = ArrayArena::new();
+    let handles: Vec<_> = (0..4).map(|i| arena.insert(i * 10).unwrap()).collect();
+    arena.remove(handles[1]);
+
+    assert_eq!(arena.iter().len(), 3);
+    assert!(arena
+        .iter()
+        .rev()
+        .map(|(h, _)| h)
+        .eq([handles[3], handles[2], handles[0]]));
+    for (handle, value) in &mut arena {
+        *value += handle.index() as u32;
+    }
+    assert_eq!(
+        format!("{:?}", arena.iter().map(|(_, v)| v).collect::<Vec<_>>()),
+        "[0, 22, 33]"
+    );
+
+    let clone = arena.clone();
+    let mut drain = arena.drain();
+    assert_eq!(drain.len(), 3);
+    assert_eq!(drain.next(), Some((handles[0], 0)));
+    drop(drain);
+    assert!(arena.is_empty());
+    assert!(handles.iter().all(|&h| !arena.contains(h)));
+    assert_eq!(clone.get(handles[3]), Some(&33));
+    assert_eq!(clone.len(), 3);
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Insert(u32),
+    Remove(usize),
+    Retain(u32),
+    Swap(usize, usize),
+    Clear,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => any::<u32>().prop_map(Op::Insert),
+        3 => any::<usize>().prop_map(Op::Remove),
+        1 => (2..5u32).prop_map(Op::Retain),
+        2 => (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Swap(a, b)),
+        1 => Just(Op::Clear),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut arena: ArrayArena<u32, 6> = ArrayArena::new();
+        let mut model = BTreeMap::new();
+        // Every handle ever issued, live or stale
+        let mut issued: Vec<Handle> = Vec::new();
+        for op in ops {
+            match op {
+                Op::Insert(value) => match arena.insert(value) {
+                    Ok(handle) => {
+                        prop_assert!(!issued.contains(&handle));
+                        issued.push(handle);
+                        model.insert(handle, value);
+                    }
+                    Err(rejected) => {
+                        prop_assert_eq!(rejected, value);
+                        prop_assert_eq!(model.len(), 6);
+                    }
+                },
+                Op::Remove(i) if !issued.is_empty() => {
+                    let handle = issued[i % issued.len()];
+                    prop_assert_eq!(arena.remove(handle), model.remove(&handle));
+                }
+                Op::Retain(m) => {
+                    arena.retain(|_, value| *value % m != 0);
+                    model.retain(|_, value| *value % m != 0);
+                }
+                Op::Swap(a, b) if !issued.is_empty() => {
+                    let (a, b) = (issued[a % issued.len()], issued[b % issued.len()]);
+                    match arena.get2_mut(a, b) {
+                        Some((x, y)) => {
+                            std::mem::swap(x, y);
+                            let (x, y) = (model[&a], model[&b]);
+                            model.insert(a, y);
+                            model.insert(b, x);
+                        }
+                        None => prop_assert!(
+                            a.index() == b.index() || !model.contains_key(&a) || !model.contains_key(&b)
+                        ),
+                    }
+                }
+                Op::Clear => {
+                    arena.clear();
+                    model.clear();
+                }
+                Op::Remove(_) | Op::Swap(..) => {}
+            }
+            prop_assert_eq!(arena.len(), model.len());
+            for handle in &issued {
+                prop_assert_eq!(arena.get(*handle), model.get(handle));
+            }
+            prop_assert!(arena.iter().map(|(h, &v)| (h, v)).eq(model.iter().map(|(&h, &v)| (h, v))));
+        }
+    }
+}
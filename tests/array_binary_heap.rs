@@ -0,0 +1,331 @@
+use std::{
+    cell::Cell,
+    cmp::Reverse,
+    panic::{self, AssertUnwindSafe},
+};
+
+use proptest::prelude::*;
+use stack_based_vec::{ArrayBinaryHeap, ArrayVec, Min, PeekMut};
+
+fn heap_of<const N: usize>(data: &[i32]) -> ArrayBinaryHeap<i32, N> {
+    data.iter().copied().collect()
+}
+
+/// Checks that every element is not greater than its parent.
+fn is_heap<T: Ord>(data: &[T]) -> bool {
+    (1..data.len()).all(|i| data[i] <= data[(i - 1) / 2])
+}
+
+#[test]
+fn test_iterator() {
+    let data = [5, 9, 3];
+    let iterout = [9, 5, 3];
+    let heap = heap_of::<4>(&data);
+    let mut v: Vec<_> = heap.iter().copied().collect();
+    v.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(v, iterout);
+    assert_eq!((&heap).into_iter().len(), 3);
+}
+
+#[test]
+fn test_default_without_default_elements() {
+    #[derive(Eq, Ord, PartialEq, PartialOrd)]
+    struct NoDefault(u8);
+
+    let mut heap: ArrayBinaryHeap<NoDefault, 2, Min> = Default::default();
+    heap.push(NoDefault(2));
+    heap.push(NoDefault(1));
+    assert!(matches!(heap.pop(), Some(NoDefault(1))));
+}
+
+#[test]
+fn test_peek_and_pop() {
+    let data = [2, 4, 6, 2, 1, 8, 10, 3, 5, 7, 0, 9, 1];
+    let mut sorted = data.to_vec();
+    sorted.sort_unstable();
+    let mut heap = heap_of::<16>(&data);
+    while !heap.is_empty() {
+        assert_eq!(heap.peek(), sorted.last());
+        assert_eq!(heap.pop(), sorted.pop());
+    }
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn test_peek_mut() {
+    let mut heap = heap_of::<16>(&[2, 4, 6, 2, 1, 8, 10, 3, 5, 7, 0, 9, 1]);
+    assert_eq!(heap.peek(), Some(&10));
+    {
+        let mut top = heap.peek_mut().unwrap();
+        assert_eq!(*top, 10);
+        *top -= 2;
+    }
+    assert_eq!(heap.peek(), Some(&9));
+    assert!(is_heap(heap.as_slice()));
+}
+
+#[test]
+fn test_peek_mut_leek() {
+    let mut heap = heap_of::<4>(&[4, 2, 7]);
+    {
+        let mut top = heap.peek_mut().unwrap();
+        *top = 0;
+        std::mem::forget(top);
+    }
+    // The heap is out of order but still holds every element exactly once.
+    let mut v = heap.into_vec();
+    v.sort_unstable();
+    assert_eq!(v, [0, 2, 4]);
+}
+
+#[test]
+fn test_peek_mut_pop() {
+    let mut heap = heap_of::<16>(&[2, 4, 6, 2, 1, 8, 10, 3, 5, 7, 0, 9, 1]);
+    assert_eq!(heap.peek(), Some(&10));
+    {
+        let mut top = heap.peek_mut().unwrap();
+        assert_eq!(*top, 10);
+        *top -= 2;
+        assert_eq!(PeekMut::pop(top), 8);
+    }
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.len(), 12);
+    assert!(is_heap(heap.as_slice()));
+}
+
+#[test]
+fn test_push() {
+    let mut heap = heap_of::<8>(&[2, 4, 9]);
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some(&9));
+    heap.push(11);
+    assert_eq!(heap.len(), 4);
+    assert_eq!(heap.peek(), Some(&11));
+    heap.push(5);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.peek(), Some(&11));
+    heap.push(27);
+    assert_eq!(heap.len(), 6);
+    assert_eq!(heap.peek(), Some(&27));
+    heap.push(3);
+    assert_eq!(heap.len(), 7);
+    assert_eq!(heap.peek(), Some(&27));
+    heap.push(103);
+    assert_eq!(heap.len(), 8);
+    assert_eq!(heap.peek(), Some(&103));
+    assert!(heap.try_push(0).is_err());
+}
+
+#[test]
+fn test_push_unique() {
+    let mut heap: ArrayBinaryHeap<Box<i32>, 8> = [2, 4, 9].iter().map(|&x| Box::new(x)).collect();
+    assert_eq!(heap.len(), 3);
+    assert_eq!(**heap.peek().unwrap(), 9);
+    heap.push(Box::new(11));
+    assert_eq!(heap.len(), 4);
+    assert_eq!(**heap.peek().unwrap(), 11);
+    heap.push(Box::new(5));
+    assert_eq!(heap.len(), 5);
+    assert_eq!(**heap.peek().unwrap(), 11);
+    heap.push(Box::new(27));
+    assert_eq!(heap.len(), 6);
+    assert_eq!(**heap.peek().unwrap(), 27);
+    heap.push(Box::new(3));
+    assert_eq!(heap.len(), 7);
+    assert_eq!(**heap.peek().unwrap(), 27);
+    heap.push(Box::new(103));
+    assert_eq!(heap.len(), 8);
+    assert_eq!(**heap.peek().unwrap(), 103);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_push_full() {
+    let mut heap = heap_of::<2>(&[1, 2]);
+    heap.push(3);
+}
+
+#[test]
+fn test_push_pop() {
+    let mut heap = heap_of::<8>(&[5, 5, 2, 1, 3]);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.push_pop(6), 6);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.push_pop(0), 5);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.push_pop(4), 5);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.push_pop(1), 4);
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.into_sorted_vec(), [0, 1, 1, 2, 3]);
+
+    let mut empty = heap_of::<1>(&[]);
+    assert_eq!(empty.push_pop(1), 1);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_replace_top() {
+    let mut heap = heap_of::<5>(&[5, 5, 2, 1, 3]);
+    assert_eq!(heap.replace_top(6), Some(5));
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.replace_top(0), Some(6));
+    assert_eq!(heap.replace_top(4), Some(5));
+    assert_eq!(heap.replace_top(1), Some(4));
+    assert_eq!(heap.into_sorted_vec(), [0, 1, 1, 2, 3]);
+
+    let mut empty = heap_of::<1>(&[]);
+    assert_eq!(empty.replace_top(1), None);
+    assert_eq!(empty.peek(), Some(&1));
+}
+
+#[test]
+fn test_to_vec() {
+    fn check(data: &[i32]) {
+        let heap = heap_of::<16>(data);
+        let mut v = heap.clone().into_vec();
+        v.sort_unstable();
+        let mut sorted = data.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(v, *sorted);
+        assert_eq!(heap.into_sorted_vec(), *sorted);
+    }
+    check(&[]);
+    check(&[5]);
+    check(&[3, 2]);
+    check(&[2, 3]);
+    check(&[5, 1, 2]);
+    check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    check(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    check(&[10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    check(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+    check(&[1, 1, 1, 1, 1, 2, 2, 2, 2, 2]);
+    check(&[2, 2, 2, 2, 2, 1, 1, 1, 1, 1]);
+    check(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 9, 8, 7, 6]);
+    check(&[5, 6, 7, 2, 3, 4, 9, 10, 1, 8]);
+}
+
+#[test]
+fn test_empty_pop() {
+    let mut heap: ArrayBinaryHeap<i32, 4> = ArrayBinaryHeap::new();
+    assert!(heap.pop().is_none());
+}
+
+#[test]
+fn test_empty_peek() {
+    let heap: ArrayBinaryHeap<i32, 4> = ArrayBinaryHeap::new();
+    assert!(heap.peek().is_none());
+}
+
+#[test]
+fn test_empty_peek_mut() {
+    let mut heap: ArrayBinaryHeap<i32, 4> = ArrayBinaryHeap::new();
+    assert!(heap.peek_mut().is_none());
+}
+
+#[test]
+fn test_from_iter() {
+    let xs = [9, 8, 7, 6, 5, 4, 3, 2, 1];
+    let mut heap: ArrayBinaryHeap<_, 9> = xs.iter().rev().copied().collect();
+    for &x in &xs {
+        assert_eq!(heap.pop().unwrap(), x);
+    }
+}
+
+#[test]
+fn test_drain() {
+    let mut heap = heap_of::<16>(&[9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(heap.drain().take(5).count(), 5);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_retain() {
+    let mut heap = heap_of::<8>(&[2, 4, 6, 1, 9, 3, 8]);
+    heap.retain(|&x| x % 3 != 0);
+    assert!(is_heap(heap.as_slice()));
+    assert_eq!(heap.into_sorted_vec(), [1, 2, 4, 8]);
+}
+
+#[test]
+fn test_min_heaps() {
+    let mut reverse: ArrayBinaryHeap<Reverse<i32>, 4> = ArrayBinaryHeap::new();
+    let mut min = ArrayBinaryHeap::<i32, 4, _>::with_comparator(Min);
+    for &x in &[3, 1, 4, 1] {
+        reverse.push(Reverse(x));
+        min.push(x);
+    }
+    assert_eq!(reverse.pop(), Some(Reverse(1)));
+    assert_eq!(min.pop(), Some(1));
+    assert_eq!(min.into_sorted_vec(), [4, 3, 1]);
+}
+
+#[test]
+fn test_closure_comparator() {
+    let by_abs = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+    let vec: ArrayVec<i32, 4> = [1, -7, 3, -2].iter().copied().collect();
+    let mut heap = ArrayBinaryHeap::from_vec_with_comparator(vec, by_abs);
+    assert_eq!(heap.pop(), Some(-7));
+    assert_eq!(heap.pop(), Some(3));
+    assert_eq!(format!("{:?}", heap.peek_mut().unwrap()), "PeekMut(-2)");
+}
+
+#[test]
+fn panic_safe() {
+    for panic_at in 0..64 {
+        let calls = Cell::new(0);
+        let cmp = |a: &u32, b: &u32| {
+            calls.set(calls.get() + 1);
+            if calls.get() == panic_at {
+                panic!("comparator panic");
+            }
+            a.cmp(b)
+        };
+        let mut heap = ArrayBinaryHeap::<u32, 16, _>::with_comparator(cmp);
+        let (mut pushed, mut popped) = (Vec::new(), Vec::new());
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            for x in (0..16).map(|i| (i * 7) % 16) {
+                pushed.push(x);
+                heap.push(x);
+            }
+            while let Some(x) = heap.pop() {
+                popped.push(x);
+            }
+        }));
+        // Nothing is duplicated, and only an element being popped when the
+        // comparator panicked can be lost.
+        let mut seen = heap.into_vec().to_vec();
+        seen.extend(popped);
+        seen.sort_unstable();
+        seen.dedup();
+        assert!(seen.len() + 1 >= pushed.len());
+        assert!(seen.iter().all(|x| pushed.contains(x)));
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_sorted_model(ops in prop::collection::vec(prop::option::of(any::<u8>()), 0..64)) {
+        let mut heap: ArrayBinaryHeap<u8, 8> = ArrayBinaryHeap::new();
+        let mut model = Vec::new();
+        for op in ops {
+            match op {
+                Some(x) if model.len() < 8 => {
+                    heap.push(x);
+                    model.push(x);
+                    model.sort_unstable();
+                }
+                Some(x) => {
+                    model.push(x);
+                    model.sort_unstable();
+                    prop_assert_eq!(heap.push_pop(x), model.pop().unwrap());
+                }
+                None => prop_assert_eq!(heap.pop(), model.pop()),
+            }
+            prop_assert!(is_heap(heap.as_slice()));
+            prop_assert_eq!(heap.peek(), model.last());
+        }
+        let sorted = heap.into_sorted_vec();
+        prop_assert_eq!(sorted.as_slice(), model.as_slice());
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::VecDeque;
+
+use stack_based_vec::{ArrayDeque, ArrayVec};
+
+/// Builds a deque and a `VecDeque` model holding `0..len`, with the deque's
+/// head placed at `head`.
+fn with_head<const N: usize>(head: usize, len: usize) -> (ArrayDeque<usize, N>, VecDeque<usize>) {
+    let mut d = ArrayDeque::new();
+    for _ in 0..head {
+        d.push_back(0);
+        d.pop_front();
+    }
+    for i in 0..len {
+        d.push_back(i);
+    }
+    (d, (0..len).collect())
+}
+
+fn contents<const N: usize>(d: &ArrayDeque<usize, N>) -> Vec<usize> {
+    let (a, b) = d.as_slices();
+    let mut v = a.to_vec();
+    v.extend_from_slice(b);
+    assert_eq!(v, d.iter().copied().collect::<Vec<_>>());
+    assert_eq!(
+        v.iter().rev().copied().collect::<Vec<_>>(),
+        d.iter().rev().copied().collect::<Vec<_>>()
+    );
+    v
+}
+
+#[test]
+fn drain_matches_vec_deque() {
+    for head in 0..5 {
+        for len in 0..=5 {
+            for start in 0..=len {
+                for end in start..=len {
+                    let (mut d, mut m) = with_head::<5>(head, len);
+                    let drained: Vec<_> = d.drain(start..end).collect();
+                    let expected: Vec<_> = m.drain(start..end).collect();
+                    assert_eq!(drained, expected);
+                    assert_eq!(contents(&d), Vec::from(m.clone()));
+
+                    // The deque must still work as a ring buffer afterwards.
+                    while d.try_push_back(99).is_ok() {
+                        m.push_back(99);
+                    }
+                    assert_eq!(contents(&d), Vec::from(m));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn drain_back_to_front() {
+    let (mut d, _) = with_head::<6>(4, 5);
+    let drained: Vec<_> = d.drain(1..4).rev().collect();
+    assert_eq!(drained, [3, 2, 1]);
+    assert_eq!(d, [0, 4]);
+}
+
+#[test]
+#[should_panic(expected = "drain range out of bounds")]
+fn drain_out_of_bounds() {
+    let (mut d, _) = with_head::<4>(0, 2);
+    d.drain(1..3);
+}
+
+#[test]
+fn rotate_matches_vec_deque() {
+    for head in 0..5 {
+        for len in 0..=5 {
+            for n in 0..=len {
+                let (mut d, mut m) = with_head::<5>(head, len);
+                d.rotate_left(n);
+                m.rotate_left(n);
+                assert_eq!(contents(&d), Vec::from(m.clone()));
+
+                d.rotate_right(n);
+                m.rotate_right(n);
+                assert_eq!(contents(&d), Vec::from(m));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "rotation amount out of bounds")]
+fn rotate_out_of_bounds() {
+    let (mut d, _) = with_head::<4>(0, 2);
+    d.rotate_left(3);
+}
+
+#[test]
+fn make_contiguous() {
+    for head in 0..5 {
+        for len in 0..=5 {
+            let (mut d, _) = with_head::<5>(head, len);
+            let expected: Vec<_> = (0..len).collect();
+            assert_eq!(d.make_contiguous(), &expected[..]);
+            assert_eq!(d.as_slices().1, &[]);
+            assert_eq!(ArrayVec::from(d), &expected[..]);
+        }
+    }
+}
+
+#[test]
+fn overwrite_variants() {
+    let mut d: ArrayDeque<i32, 3> = ArrayDeque::new();
+    for i in 0..5 {
+        d.push_back_overwrite(i);
+    }
+    assert_eq!(d, [2, 3, 4]);
+    assert_eq!(d.push_front_overwrite(1), Some(4));
+    assert_eq!(d, [1, 2, 3]);
+
+    let mut empty: ArrayDeque<i32, 0> = ArrayDeque::new();
+    assert_eq!(empty.push_back_overwrite(1), Some(1));
+    assert_eq!(empty.push_front_overwrite(2), Some(2));
+}
+
+#[test]
+fn full_deque_rejects_pushes() {
+    let mut d: ArrayDeque<i32, 2> = ArrayDeque::new();
+    d.push_front(1);
+    d.push_front(0);
+    assert_eq!(d.try_push_back(2).unwrap_err().element(), 2);
+    assert_eq!(d.try_push_front(-1).unwrap_err().element(), -1);
+    assert_eq!(d, [0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn push_front_overflow() {
+    let mut d: ArrayDeque<i32, 1> = ArrayDeque::new();
+    d.push_back(0);
+    d.push_front(1);
+}
+
+#[test]
+fn from_array_vec() {
+    let v = ArrayVec::<_, 4>::from_partial_array([1, 2, 3]);
+    let mut d = ArrayDeque::from(v);
+    d.push_front(0);
+    assert_eq!(d, [0, 1, 2, 3]);
+    assert_eq!(ArrayVec::from(d), [0, 1, 2, 3]);
+}
+
+#[test]
+fn accessors() {
+    let (mut d, _) = with_head::<4>(3, 3);
+    assert_eq!(d.front(), Some(&0));
+    assert_eq!(d.back(), Some(&2));
+    assert_eq!(d[1], 1);
+    *d.back_mut().unwrap() = 7;
+    d[0] = 5;
+    for x in d.iter_mut() {
+        *x += 1;
+    }
+    assert_eq!(d, [6, 2, 8]);
+    assert!(d.contains(&8));
+    assert_eq!(d.get(3), None);
+    assert_eq!(d.iter().len(), 3);
+    assert_eq!(d.clone(), d);
+    assert_eq!(format!("{:?}", d), "[6, 2, 8]");
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use stack_based_vec::{ArrayMap, Entry, Order, Search, SortedArrayMap};
+
+#[derive(Clone, Debug)]
+enum Op {
+    Insert(u8, u32),
+    Remove(u8),
+    Entry(u8),
+    Retain(u8),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..24u8, any::<u32>()).prop_map(|(k, v)| Op::Insert(k, v)),
+        (0..24u8).prop_map(Op::Remove),
+        (0..24u8).prop_map(Op::Entry),
+        (2..6u8).prop_map(Op::Retain),
+    ]
+}
+
+/// Applies `ops` to `map` and to a `BTreeMap`, checking that they agree.
+fn check_model<O>(map: &mut ArrayMap<u8, u32, 8, O>, ops: Vec<Op>) -> Result<(), TestCaseError>
+where
+    O: Search<u8>,
+{
+    let mut model = BTreeMap::new();
+    for op in ops {
+        match op {
+            Op::Insert(k, v) => {
+                if model.len() < 8 || model.contains_key(&k) {
+                    prop_assert_eq!(map.insert(k, v), model.insert(k, v));
+                } else {
+                    prop_assert_eq!(map.try_insert(k, v).unwrap_err().element(), (k, v));
+                }
+            }
+            Op::Remove(k) => prop_assert_eq!(map.remove(&k), model.remove(&k)),
+            Op::Entry(k) => match map.entry(k) {
+                Entry::Occupied(entry) => {
+                    prop_assert_eq!(entry.remove_entry(), model.remove_entry(&k).unwrap());
+                }
+                Entry::Vacant(entry) => {
+                    prop_assert!(!model.contains_key(&k));
+                    if let Ok(v) = entry.try_insert(u32::from(k)) {
+                        *v += 1;
+                        model.insert(k, u32::from(k) + 1);
+                    } else {
+                        prop_assert_eq!(model.len(), 8);
+                    }
+                }
+            },
+            Op::Retain(m) => {
+                map.retain(|k, _| k % m != 0);
+                model.retain(|k, _| k % m != 0);
+            }
+        }
+        prop_assert_eq!(map.len(), model.len());
+        for k in 0..24 {
+            prop_assert_eq!(map.get(&k), model.get(&k));
+        }
+    }
+    Ok(())
+}
+
+fn sorted_entries<O: Order>(map: &ArrayMap<u8, u32, 8, O>) -> Vec<(u8, u32)> {
+    let mut entries: Vec<_> = map.iter().map(|(&k, &v)| (k, v)).collect();
+    entries.sort_unstable();
+    entries
+}
+
+#[test]
+fn entry_api() {
+    let mut map: ArrayMap<&str, Vec<u8>, 2> = ArrayMap::new();
+    map.entry("a").or_default().push(1);
+    map.entry("a").and_modify(|v| v.push(2)).or_default();
+    assert_eq!(map["a"], [1, 2]);
+
+    match map.entry("a") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"a");
+            assert_eq!(entry.insert(vec![3]), [1, 2]);
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    map.insert("b", vec![]);
+    match map.entry("c") {
+        Entry::Vacant(entry) => {
+            assert_eq!(entry.key(), &"c");
+            let (key, value) = entry.try_insert(vec![4]).unwrap_err().element();
+            assert_eq!((key, value), ("c", vec![4]));
+        }
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn entry_or_insert_full() {
+    let mut map: ArrayMap<u8, u8, 1> = ArrayMap::new();
+    map.insert(1, 1);
+    map.entry(2).or_insert(2);
+}
+
+#[test]
+fn sorted_iteration_order() {
+    let map: SortedArrayMap<u8, char, 4> = [(3, 'c'), (1, 'a'), (2, 'b')].iter().copied().collect();
+    assert!(map.keys().eq(&[1, 2, 3]));
+    assert!(map.values().rev().eq(&['c', 'b', 'a']));
+    assert_eq!(map.as_slice(), &[(1, 'a'), (2, 'b'), (3, 'c')]);
+}
+
+#[test]
+fn unsorted_remove_swaps_last() {
+    let mut map: ArrayMap<u8, char, 4> = [(1, 'a'), (2, 'b'), (3, 'c')].iter().copied().collect();
+    map.remove(&1);
+    assert_eq!(map.as_slice(), &[(3, 'c'), (2, 'b')]);
+}
+
+#[test]
+fn equality_ignores_order_and_capacity() {
+    let a: ArrayMap<u8, char, 4> = [(1, 'a'), (2, 'b')].iter().copied().collect();
+    let b: ArrayMap<u8, char, 8> = [(2, 'b'), (1, 'a')].iter().copied().collect();
+    assert_eq!(a, b);
+    let c: ArrayMap<u8, char, 8> = [(2, 'b'), (1, 'c')].iter().copied().collect();
+    assert_ne!(a, c);
+}
+
+#[test]
+fn values_mut_and_iter_mut() {
+    let mut map: ArrayMap<u8, u32, 4> = [(1, 10), (2, 20)].iter().copied().collect();
+    for v in map.values_mut() {
+        *v += 1;
+    }
+    for (k, v) in &mut map {
+        *v += u32::from(*k);
+    }
+    assert_eq!(map.get(&1), Some(&12));
+    assert_eq!(map.get(&2), Some(&23));
+    assert_eq!(format!("{:?}", map), "{1: 12, 2: 23}");
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn from_iter_overflow() {
+    let _: ArrayMap<u8, u8, 2> = (0..3).map(|i| (i, i)).collect();
+}
+
+proptest! {
+    #[test]
+    fn unsorted_matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut map: ArrayMap<u8, u32, 8> = ArrayMap::new();
+        check_model(&mut map, ops)?;
+    }
+
+    #[test]
+    fn sorted_matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut map = SortedArrayMap::new();
+        check_model(&mut map, ops)?;
+        prop_assert!(map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b));
+    }
+
+    #[test]
+    fn modes_agree(entries in prop::collection::vec((0..16u8, any::<u32>()), 0..8)) {
+        let unsorted: ArrayMap<u8, u32, 8> = entries.iter().copied().collect();
+        let sorted: SortedArrayMap<u8, u32, 8> = entries.iter().copied().collect();
+        prop_assert_eq!(sorted_entries(&unsorted), sorted_entries(&sorted));
+    }
+}
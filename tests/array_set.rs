@@ -0,0 +1,133 @@
+use std::collections::BTreeSet;
+
+use proptest::prelude::*;
+use stack_based_vec::{ArraySet, Search, SortedArraySet};
+
+fn model<'a>(iter: impl Iterator<Item = &'a u8>) -> BTreeSet<u8> {
+    iter.copied().collect()
+}
+
+/// Checks every set operation of `a` and `b` against `BTreeSet`.
+fn check_algebra<O>(a: &ArraySet<u8, 8, O>, b: &ArraySet<u8, 8, O>) -> Result<(), TestCaseError>
+where
+    O: Search<u8>,
+{
+    let (ma, mb) = (model(a.iter()), model(b.iter()));
+
+    prop_assert_eq!(model(a.union(b)), &ma | &mb);
+    prop_assert_eq!(model(a.intersection(b)), &ma & &mb);
+    prop_assert_eq!(model(a.difference(b)), &ma - &mb);
+    prop_assert_eq!(model(a.symmetric_difference(b)), &ma ^ &mb);
+    prop_assert_eq!(a.union(b).count(), (&ma | &mb).len());
+    prop_assert_eq!(a.symmetric_difference(b).count(), (&ma ^ &mb).len());
+
+    prop_assert_eq!(a.is_subset(b), ma.is_subset(&mb));
+    prop_assert_eq!(a.is_superset(b), ma.is_superset(&mb));
+    prop_assert_eq!(a.is_disjoint(b), ma.is_disjoint(&mb));
+    prop_assert_eq!(a == b, ma == mb);
+    Ok(())
+}
+
+#[test]
+fn insert_reports_presence_and_overflow() {
+    let mut set: SortedArraySet<u8, 2> = SortedArraySet::new();
+    assert_eq!(set.insert(2), Ok(true));
+    assert_eq!(set.insert(1), Ok(true));
+    assert_eq!(set.insert(2), Ok(false));
+    assert_eq!(set.insert(3).unwrap_err().element(), 3);
+    assert!(set.iter().eq(&[1, 2]));
+}
+
+#[test]
+fn try_from_iter_reports_overflow() {
+    let (set, rest) = ArraySet::<char, 2>::try_from_iter("abacd".chars())
+        .unwrap_err()
+        .element();
+    assert_eq!(
+        set,
+        ArraySet::<char, 2>::try_from_iter("ba".chars()).unwrap()
+    );
+    assert_eq!(rest.collect::<String>(), "cd");
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn from_iter_overflow() {
+    let _: ArraySet<u8, 2> = (0..3).collect();
+}
+
+#[test]
+fn borrowed_lookups() {
+    let mut set: ArraySet<String, 4> = ArraySet::new();
+    set.insert("a".to_string()).unwrap();
+    assert!(set.contains("a"));
+    assert_eq!(set.get("a").map(String::as_str), Some("a"));
+    assert_eq!(set.take("a").as_deref(), Some("a"));
+    assert!(!set.remove("a"));
+}
+
+#[test]
+fn sorted_union_and_symmetric_difference_are_ordered() {
+    let a: SortedArraySet<u8, 4> = [5, 1, 3].iter().copied().collect();
+    let b: SortedArraySet<u8, 4> = [4, 0, 3, 2].iter().copied().collect();
+    assert!(a.union(&b).eq(&[0, 1, 2, 3, 4, 5]));
+    assert!(a.symmetric_difference(&b).eq(&[0, 1, 2, 4, 5]));
+    assert!(b.union(&a).eq(&[0, 1, 2, 3, 4, 5]));
+    assert_eq!(a.union(&b).size_hint(), (3, Some(7)));
+}
+
+#[test]
+fn into_iter_and_debug() {
+    let set: SortedArraySet<u8, 4> = [3, 1, 2].iter().copied().collect();
+    assert_eq!(format!("{:?}", set), "{1, 2, 3}");
+    assert!(set.clone().into_iter().rev().eq([3, 2, 1]));
+    let mut set = set;
+    set.retain(|&x| x != 2);
+    assert!(set.into_iter().eq([1, 3]));
+}
+
+proptest! {
+    #[test]
+    fn unsorted_algebra_matches_model(
+        a in prop::collection::vec(0..12u8, 0..=8),
+        b in prop::collection::vec(0..12u8, 0..=8),
+    ) {
+        let a: ArraySet<u8, 8> = a.into_iter().collect();
+        let b: ArraySet<u8, 8> = b.into_iter().collect();
+        check_algebra(&a, &b)?;
+    }
+
+    #[test]
+    fn sorted_algebra_matches_model(
+        a in prop::collection::vec(0..12u8, 0..=8),
+        b in prop::collection::vec(0..12u8, 0..=8),
+    ) {
+        let a: SortedArraySet<u8, 8> = a.into_iter().collect();
+        let b: SortedArraySet<u8, 8> = b.into_iter().collect();
+        prop_assert!(a.iter().zip(a.iter().skip(1)).all(|(x, y)| x < y));
+        check_algebra(&a, &b)?;
+
+        let (ma, mb) = (model(a.iter()), model(b.iter()));
+        prop_assert!(a.union(&b).eq((&ma | &mb).iter()));
+        prop_assert!(a.intersection(&b).eq((&ma & &mb).iter()));
+        prop_assert!(a.difference(&b).eq((&ma - &mb).iter()));
+        prop_assert!(a.symmetric_difference(&b).eq((&ma ^ &mb).iter()));
+    }
+
+    #[test]
+    fn insert_and_remove_match_model(ops in prop::collection::vec((any::<bool>(), 0..12u8), 0..64)) {
+        let mut set: ArraySet<u8, 6> = ArraySet::new();
+        let mut model = BTreeSet::new();
+        for (insert, value) in ops {
+            if insert {
+                match set.insert(value) {
+                    Ok(new) => prop_assert_eq!(new, model.insert(value)),
+                    Err(_) => prop_assert!(model.len() == 6 && !model.contains(&value)),
+                }
+            } else {
+                prop_assert_eq!(set.remove(&value), model.remove(&value));
+            }
+            prop_assert_eq!(set.len(), model.len());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use proptest::prelude::*;
+use stack_based_vec::{ArraySlab, Key};
+
+#[test]
+fn keys_are_stable_and_reused() {
+    let mut slab: ArraySlab<char, 3> = ArraySlab::new();
+    let a = slab.insert('a').unwrap();
+    let b = slab.insert('b').unwrap();
+    let c = slab.insert('c').unwrap();
+    assert!(slab.is_full());
+    assert_eq!(slab.vacant_key(), None);
+
+    assert_eq!(slab.remove(b), Some('b'));
+    assert_eq!(slab.remove(b), None);
+    assert!(!slab.contains(b));
+    assert_eq!((slab[a], slab[c]), ('a', 'c'));
+
+    assert_eq!(slab.remove(a), Some('a'));
+    // The most recently freed slot is reused first.
+    assert_eq!(slab.vacant_key(), Some(a));
+    assert_eq!(slab.insert('d'), Ok(a));
+    assert_eq!(slab.insert('e'), Ok(b));
+    assert_eq!(slab.insert('f'), Err('f'));
+}
+
+#[test]
+fn out_of_range_keys() {
+    let mut slab: ArraySlab<u8, 2> = ArraySlab::new();
+    assert_eq!(slab.get(Key::new(5)), None);
+    assert_eq!(slab.remove(Key::new(1)), None);
+    assert_eq!(slab.get_mut(Key::new(0)), None);
+}
+
+#[test]
+#[should_panic(expected = "invalid key")]
+fn index_vacant() {
+    let mut slab: ArraySlab<u8, 2> = ArraySlab::new();
+    let key = slab.insert(1).unwrap();
+    slab.remove(key);
+    let _ = slab[key];
+}
+
+#[test]
+fn iteration() {
+    let mut slab: ArraySlab<u32, 4> = ArraySlab::new();
+    let keys: Vec<_> = (0..4).map(|i| slab.insert(i * 10).unwrap()).collect();
+    slab.remove(keys[1]);
+
+    let iter = slab.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(
+        format!("{:?}", iter),
+        "[(Key(0), 0), (Key(2), 20), (Key(3), 30)]"
+    );
+    assert!(slab
+        .iter()
+        .rev()
+        .map(|(key, _)| key)
+        .eq([keys[3], keys[2], keys[0]]));
+
+    for (key, value) in &mut slab {
+        *value += key.index() as u32;
+    }
+    assert_eq!(format!("{:?}", slab), "{Key(0): 0, Key(2): 22, Key(3): 33}");
+    let mut into_iter = slab.into_iter();
+    assert_eq!(into_iter.next_back(), Some((keys[3], 33)));
+    assert_eq!(into_iter.len(), 2);
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Insert(u32),
+    Remove(usize),
+    Retain(u32),
+    Clear,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => any::<u32>().prop_map(Op::Insert),
+        3 => (0..10usize).prop_map(Op::Remove),
+        1 => (2..5u32).prop_map(Op::Retain),
+        1 => Just(Op::Clear),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_model(ops in prop::collection::vec(op(), 0..64)) {
+        let mut slab: ArraySlab<u32, 8> = ArraySlab::new();
+        let mut model = BTreeMap::new();
+        for op in ops {
+            match op {
+                Op::Insert(value) => match slab.insert(value) {
+                    Ok(key) => {
+                        prop_assert!(key.index() < 8);
+                        prop_assert_eq!(model.insert(key.index(), value), None);
+                    }
+                    Err(rejected) => {
+                        prop_assert_eq!(rejected, value);
+                        prop_assert_eq!(model.len(), 8);
+                    }
+                },
+                Op::Remove(idx) => prop_assert_eq!(slab.remove(Key::new(idx)), model.remove(&idx)),
+                Op::Retain(m) => {
+                    slab.retain(|_, value| *value % m != 0);
+                    model.retain(|_, value| *value % m != 0);
+                }
+                Op::Clear => {
+                    slab.clear();
+                    model.clear();
+                }
+            }
+            prop_assert_eq!(slab.len(), model.len());
+            prop_assert!(slab.iter().map(|(key, &value)| (key.index(), value)).eq(model.iter().map(|(&k, &v)| (k, v))));
+        }
+    }
+}
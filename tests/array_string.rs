@@ -0,0 +1,155 @@
+use core::fmt::Write;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use stack_based_vec::{array_string, ArrayString, ArrayVec};
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn const_macro() {
+    const S: ArrayString<8> = array_string!("const");
+    assert_eq!(S, "const");
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn macro_overflow() {
+    let _: ArrayString<2> = array_string!("abc");
+}
+
+#[test]
+fn hash_matches_str() {
+    let s: ArrayString<8> = array_string!("key");
+    assert_eq!(hash_of(&s), hash_of("key"));
+
+    let mut map = HashMap::new();
+    map.insert(s, 1);
+    assert_eq!(map.get("key"), Some(&1));
+}
+
+#[test]
+fn comparisons() {
+    let s: ArrayString<8> = array_string!("abc");
+    let t: ArrayString<4> = array_string!("abc");
+    assert_eq!(s, t);
+    assert_eq!(s, *"abc");
+    assert_eq!("abc", s);
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(s, String::from("abc"));
+        assert_eq!(String::from("abc"), s);
+    }
+    assert!(s < array_string!("abd"));
+}
+
+#[test]
+fn write_multibyte_at_boundary() {
+    let mut s: ArrayString<6> = ArrayString::new();
+    let tail = "éé";
+    write!(s, "a{}", tail).unwrap();
+    assert_eq!(s, "aéé");
+
+    // One byte left, the next char needs two.
+    assert!(s.write_char('é').is_err());
+    assert!(s.write_char('e').is_ok());
+    assert_eq!(s.len(), s.capacity());
+}
+
+#[test]
+fn from_str() {
+    let s: ArrayString<4> = "abcd".parse().unwrap();
+    assert_eq!(s, "abcd");
+    assert!("abcde".parse::<ArrayString<4>>().is_err());
+}
+
+#[test]
+fn from_utf8() {
+    let s = ArrayString::from_utf8(ArrayVec::from_array(*b"ok")).unwrap();
+    assert_eq!(s, "ok");
+
+    let err = ArrayString::from_utf8(ArrayVec::from_array([b'a', 0xc3])).unwrap_err();
+    assert_eq!(err.utf8_error().valid_up_to(), 1);
+    assert_eq!(err.into_bytes(), [b'a', 0xc3]);
+}
+
+#[test]
+#[should_panic(expected = "char boundary")]
+fn truncate_inside_char() {
+    let mut s: ArrayString<8> = array_string!("é");
+    s.truncate(1);
+}
+
+#[test]
+#[should_panic(expected = "char boundary")]
+fn insert_inside_char() {
+    let mut s: ArrayString<8> = array_string!("é");
+    s.insert(1, 'a');
+}
+
+#[test]
+fn insert_remove() {
+    let mut s: ArrayString<8> = array_string!("aé");
+    s.insert(0, 'ß');
+    s.insert(s.len(), 'z');
+    assert_eq!(s, "ßaéz");
+    assert_eq!(s.remove(2), 'a');
+    assert_eq!(s.remove(0), 'ß');
+    assert_eq!(s, "éz");
+}
+
+#[test]
+fn retain_multibyte() {
+    let mut s: ArrayString<16> = array_string!("aßcédé");
+    s.retain(|c| c.is_ascii());
+    assert_eq!(s, "acd");
+}
+
+#[test]
+fn retain_panic_keeps_valid_prefix() {
+    let mut s: ArrayString<16> = array_string!("aßcdé");
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        s.retain(|c| match c {
+            'd' => panic!(),
+            c => c != 'ß',
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(s, "ac");
+}
+
+#[test]
+fn drain() {
+    let mut s: ArrayString<16> = array_string!("aßcdé");
+    {
+        let mut drain = s.drain(1..4);
+        assert_eq!(drain.next_back(), Some('c'));
+        assert_eq!(drain.as_str(), "ß");
+    }
+    assert_eq!(s, "adé");
+
+    let all: String = s.drain(..).collect();
+    assert_eq!(all, "adé");
+    assert!(s.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "char boundary")]
+fn drain_inside_char() {
+    let mut s: ArrayString<8> = array_string!("aé");
+    s.drain(..2);
+}
+
+#[test]
+#[should_panic(expected = "drain range out of bounds")]
+fn drain_out_of_bounds() {
+    let mut s: ArrayString<8> = array_string!("ab");
+    s.drain(1..3);
+}
@@ -0,0 +1,69 @@
+use stack_based_vec::{ArrayVec, CopyArrayVec};
+
+fn assert_copy<T: Copy>(_: T) {}
+
+#[test]
+fn copies_are_independent() {
+    let mut a: CopyArrayVec<u8, 4> = CopyArrayVec::new();
+    a.push(1);
+    a.push(2);
+    assert_copy(a);
+    let mut b = a;
+    b.push(3);
+    b[0] = 9;
+    assert_eq!(a, [1, 2]);
+    assert_eq!(b, [9, 2, 3]);
+    assert_eq!(format!("{:?}", b), "[9, 2, 3]");
+}
+
+#[test]
+fn converts_to_and_from_array_vec() {
+    let v: ArrayVec<u8, 3> = ArrayVec::from_partial_array([1, 2]);
+    let copy = CopyArrayVec::from(&v);
+    let mut back = ArrayVec::from(copy);
+    back.push(3);
+    assert_eq!(v, [1, 2]);
+    assert_eq!(back, [1, 2, 3]);
+    let mut full = CopyArrayVec::from(back);
+    assert!(full.is_full());
+    assert_eq!(full.try_push(4).unwrap_err().element(), 4);
+    assert_eq!(full.pop(), Some(3));
+    full.truncate(1);
+    assert_eq!(full.as_slice(), [1]);
+}
+
+#[test]
+fn slice_operations() {
+    let mut v: CopyArrayVec<u8, 4> = CopyArrayVec::new();
+    v.extend_from_slice(&[3, 1, 2]);
+    assert_eq!(v.len(), 3);
+    assert_eq!(
+        v.try_extend_from_slice(&[4, 5]).unwrap_err().element(),
+        [4, 5]
+    );
+    assert_eq!(v, [3, 1, 2]);
+
+    v.sort_unstable();
+    for x in &mut v {
+        *x *= 2;
+    }
+    assert!(v.iter().eq(&[2, 4, 6]));
+    assert_eq!(v.first(), Some(&2));
+
+    v.clear();
+    assert!(v.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn extend_from_slice_overflow() {
+    let mut v: CopyArrayVec<u8, 2> = CopyArrayVec::new();
+    v.extend_from_slice(&[1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn push_full() {
+    let mut v: CopyArrayVec<u8, 0> = CopyArrayVec::default();
+    v.push(1);
+}
@@ -0,0 +1,909 @@
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+};
+
+use stack_based_vec::{ArrayArena, ArrayDeque, ArraySlab, ArrayVec};
+
+/// Counts how many tracked values have been created and dropped.
+#[derive(Default)]
+struct Tracker {
+    created: Cell<usize>,
+    dropped: Cell<usize>,
+}
+
+impl Tracker {
+    fn new_item(&self, value: i32) -> Item<'_> {
+        self.created.set(self.created.get() + 1);
+        Item {
+            value,
+            tracker: self,
+            panic_on_drop: false,
+            panic_on_clone: false,
+        }
+    }
+
+    fn alive(&self) -> usize {
+        self.created.get() - self.dropped.get()
+    }
+
+    fn dropped(&self) -> usize {
+        self.dropped.get()
+    }
+}
+
+struct Item<'a> {
+    value: i32,
+    tracker: &'a Tracker,
+    panic_on_drop: bool,
+    panic_on_clone: bool,
+}
+
+impl Item<'_> {
+    fn panicking_on_drop(mut self) -> Self {
+        self.panic_on_drop = true;
+        self
+    }
+
+    fn panicking_on_clone(mut self) -> Self {
+        self.panic_on_clone = true;
+        self
+    }
+}
+
+impl Clone for Item<'_> {
+    fn clone(&self) -> Self {
+        if self.panic_on_clone {
+            panic!("clone of {}", self.value);
+        }
+        self.tracker.new_item(self.value)
+    }
+}
+
+impl Drop for Item<'_> {
+    fn drop(&mut self) {
+        self.tracker.dropped.set(self.tracker.dropped.get() + 1);
+        if self.panic_on_drop && !std::thread::panicking() {
+            panic!("drop of {}", self.value);
+        }
+    }
+}
+
+fn filled<'a, const N: usize>(tracker: &'a Tracker, len: i32) -> ArrayVec<Item<'a>, N> {
+    let mut v = ArrayVec::new();
+    for value in 0..len {
+        v.push(tracker.new_item(value));
+    }
+    v
+}
+
+fn values(v: &[Item<'_>]) -> Vec<i32> {
+    v.iter().map(|item| item.value).collect()
+}
+
+fn catch<R>(f: impl FnOnce() -> R) {
+    assert!(panic::catch_unwind(AssertUnwindSafe(f)).is_err());
+}
+
+#[test]
+fn drop_releases_live_elements() {
+    let tracker = Tracker::default();
+    {
+        let _v = filled::<8>(&tracker, 5);
+        assert_eq!(tracker.alive(), 5);
+    }
+    assert_eq!(tracker.dropped(), 5);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn drop_continues_after_panic() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_drop());
+    v.push(tracker.new_item(3));
+    catch(move || drop(v));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn truncate_drops_tail() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    v.truncate(2);
+    assert_eq!(tracker.dropped(), 4);
+    assert_eq!(values(&v), [0, 1]);
+    v.truncate(10);
+    assert_eq!(tracker.dropped(), 4);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn truncate_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_drop());
+    v.push(tracker.new_item(3));
+    catch(|| v.truncate(1));
+    assert_eq!(v.len(), 1);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn clear_drops_everything() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 3);
+    v.clear();
+    assert!(v.is_empty());
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn clear_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 1);
+    v.push(tracker.new_item(1).panicking_on_drop());
+    v.push(tracker.new_item(2));
+    catch(|| v.clear());
+    assert!(v.is_empty());
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn retain_drops_removed() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    v.retain(|item| item.value % 2 == 0);
+    assert_eq!(values(&v), [0, 2, 4]);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn retain_panicking_predicate() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    catch(|| {
+        v.retain(|item| {
+            if item.value == 3 {
+                panic!("predicate");
+            }
+            item.value % 2 == 0
+        })
+    });
+    assert!(v.len() <= 6);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn retain_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_drop());
+    v.push(tracker.new_item(3));
+    catch(|| v.retain(|item| item.value == 0));
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn dedup_by_drops_duplicates() {
+    let tracker = Tracker::default();
+    let mut v: ArrayVec<_, 8> = ArrayVec::new();
+    for value in [1, 1, 2, 2, 2, 3] {
+        v.push(tracker.new_item(value));
+    }
+    v.dedup_by(|a, b| a.value == b.value);
+    assert_eq!(values(&v), [1, 2, 3]);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn dedup_by_panicking_predicate() {
+    let tracker = Tracker::default();
+    let mut v: ArrayVec<_, 8> = ArrayVec::new();
+    for value in [1, 1, 2, 2, 3, 3] {
+        v.push(tracker.new_item(value));
+    }
+    catch(|| {
+        v.dedup_by(|a, b| {
+            if a.value == 3 {
+                panic!("same_bucket");
+            }
+            a.value == b.value
+        })
+    });
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn split_off_moves_ownership() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 5);
+    let tail = v.split_off(2);
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(values(&tail), [2, 3, 4]);
+    drop(tail);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn split_off_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 3);
+    v.push(tracker.new_item(3).panicking_on_drop());
+    v.push(tracker.new_item(4));
+    let tail = v.split_off(1);
+    catch(move || drop(tail));
+    assert_eq!(tracker.dropped(), 4);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn drain_drops_unyielded() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    {
+        let mut drain = v.drain(1..4);
+        let item = drain.next().unwrap();
+        assert_eq!(item.value, 1);
+    }
+    assert_eq!(values(&v), [0, 4, 5]);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn drain_leaked_forgets_range() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    core::mem::forget(v.drain(1..));
+    assert_eq!(values(&v), [0]);
+    drop(v);
+    assert_eq!(tracker.dropped(), 1);
+}
+
+#[test]
+fn drain_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_drop());
+    v.push(tracker.new_item(3));
+    v.push(tracker.new_item(4));
+    catch(|| drop(v.drain(1..4)));
+    assert_eq!(values(&v), [0, 4]);
+    assert_eq!(tracker.dropped(), 3);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_drops_replaced() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    let replacement = [
+        tracker.new_item(10),
+        tracker.new_item(11),
+        tracker.new_item(12),
+    ];
+    drop(v.splice(1..3, replacement));
+    assert_eq!(values(&v), [0, 10, 11, 12, 3]);
+    assert_eq!(tracker.dropped(), 2);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_beyond_capacity() {
+    let tracker = Tracker::default();
+    let mut v = filled::<4>(&tracker, 3);
+    let replacement: Vec<_> = (10..16).map(|value| tracker.new_item(value)).collect();
+    let splice = v.splice(1..2, replacement.into_iter().filter(|_| true));
+    let rest = match splice.try_finish() {
+        Ok(()) => panic!("expected overflow"),
+        Err(err) => err.element(),
+    };
+    assert_eq!(
+        rest.map(|item| item.value).collect::<Vec<_>>(),
+        [12, 13, 14, 15]
+    );
+    assert_eq!(values(&v), [0, 10, 11, 2]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_beyond_capacity_drops_overflow() {
+    let tracker = Tracker::default();
+    let mut v = filled::<4>(&tracker, 3);
+    let replacement = [
+        tracker.new_item(10),
+        tracker.new_item(11),
+        tracker.new_item(12),
+    ];
+    drop(v.splice(1..2, replacement));
+    assert_eq!(values(&v), [0, 10, 11, 2]);
+    // The removed element and the replacement that did not fit
+    assert_eq!(tracker.dropped(), 2);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_beyond_capacity_while_unwinding() {
+    let tracker = Tracker::default();
+    let mut v = filled::<4>(&tracker, 3);
+    let replacement: Vec<_> = (10..13).map(|value| tracker.new_item(value)).collect();
+    catch(|| {
+        let _splice = v.splice(1..2, replacement);
+        panic!("caller panic");
+    });
+    assert_eq!(values(&v), [0, 10, 11, 2]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_try_finish_panicking_replacement() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    let replace_with = (10..).map(|value| {
+        if value == 12 {
+            panic!("replace_with");
+        }
+        tracker.new_item(value)
+    });
+    catch(|| v.splice(1..2, replace_with).try_finish());
+    assert_eq!(values(&v), [0, 10, 11, 2, 3]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn splice_panicking_replacement() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    let replace_with = (10..).map(|value| {
+        if value == 12 {
+            panic!("replace_with");
+        }
+        tracker.new_item(value)
+    });
+    catch(|| drop(v.splice(1..2, replace_with)));
+    assert_eq!(values(&v), [0, 10, 11, 2, 3]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn clone_from_drops_previous() {
+    let tracker = Tracker::default();
+    let source = filled::<8>(&tracker, 2);
+    let mut v = filled::<8>(&tracker, 5);
+    v.clone_from(&source);
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(tracker.dropped(), 5);
+    drop((v, source));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn clone_from_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut source = filled::<8>(&tracker, 2);
+    source.push(tracker.new_item(2).panicking_on_clone());
+    source.push(tracker.new_item(3));
+    let mut v = filled::<8>(&tracker, 3);
+    catch(|| v.clone_from(&source));
+    assert_eq!(values(&v), [0, 1]);
+    drop((v, source));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn clone_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut source = filled::<8>(&tracker, 2);
+    source.push(tracker.new_item(2).panicking_on_clone());
+    catch(|| source.clone());
+    assert_eq!(tracker.alive(), 3);
+    drop(source);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn into_iter_drops_unyielded() {
+    let tracker = Tracker::default();
+    let v = filled::<8>(&tracker, 5);
+    let mut iter = v.into_iter();
+    assert_eq!(iter.next().unwrap().value, 0);
+    assert_eq!(iter.next_back().unwrap().value, 4);
+    assert_eq!(values(iter.as_slice()), [1, 2, 3]);
+    assert_eq!(tracker.dropped(), 2);
+    drop(iter);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn into_iter_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_drop());
+    v.push(tracker.new_item(3));
+    let mut iter = v.into_iter();
+    iter.next();
+    catch(move || drop(iter));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn into_iter_clone() {
+    let tracker = Tracker::default();
+    let mut iter = filled::<8>(&tracker, 4).into_iter();
+    iter.next();
+    let cloned = iter.clone();
+    assert_eq!(values(cloned.as_slice()), [1, 2, 3]);
+    assert_eq!(tracker.alive(), 6);
+    drop((iter, cloned));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn try_from_iter_overflow_keeps_elements() {
+    let tracker = Tracker::default();
+    let items = (0..6).map(|value| tracker.new_item(value));
+    let (v, rest) = match ArrayVec::<_, 4>::try_from_iter(items) {
+        Ok(_) => panic!("expected overflow"),
+        Err(err) => err.element(),
+    };
+    assert_eq!(values(&v), [0, 1, 2, 3]);
+    assert_eq!(tracker.alive(), 5);
+    assert_eq!(rest.map(|item| item.value).collect::<Vec<_>>(), [4, 5]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn from_iter_overflow_panics() {
+    let tracker = Tracker::default();
+    catch(|| {
+        (0..6)
+            .map(|value| tracker.new_item(value))
+            .collect::<ArrayVec<_, 4>>()
+    });
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn try_extend_panicking_iterator() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    catch(|| {
+        v.try_extend((2..).map(|value| {
+            if value == 4 {
+                panic!("iterator");
+            }
+            tracker.new_item(value)
+        }))
+    });
+    assert_eq!(values(&v), [0, 1, 2, 3]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+/// A deque holding `0..len` whose contents wrap around the end of the buffer.
+fn wrapped_deque<'a, const N: usize>(tracker: &'a Tracker, len: i32) -> ArrayDeque<Item<'a>, N> {
+    let mut d = ArrayDeque::new();
+    for value in (0..len).rev() {
+        d.push_front(tracker.new_item(value));
+    }
+    d
+}
+
+fn deque_values(d: &ArrayDeque<Item<'_>, 8>) -> Vec<i32> {
+    d.iter().map(|item| item.value).collect()
+}
+
+#[test]
+fn deque_drop_wrapped() {
+    let tracker = Tracker::default();
+    let mut d = wrapped_deque::<8>(&tracker, 6);
+    assert_eq!(
+        d.push_back_overwrite(tracker.new_item(6)).map(|i| i.value),
+        None
+    );
+    d.truncate(4);
+    assert_eq!(tracker.dropped(), 3);
+    drop(d);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn deque_overwrite_returns_evicted() {
+    let tracker = Tracker::default();
+    let mut d = wrapped_deque::<8>(&tracker, 8);
+    let evicted = d.push_back_overwrite(tracker.new_item(8)).unwrap();
+    assert_eq!(evicted.value, 0);
+    drop(evicted);
+    assert_eq!(tracker.dropped(), 1);
+    assert_eq!(deque_values(&d), [1, 2, 3, 4, 5, 6, 7, 8]);
+    drop(d);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn deque_drain_panicking_drop() {
+    let tracker = Tracker::default();
+    let mut d = wrapped_deque::<8>(&tracker, 2);
+    d.push_back(tracker.new_item(2).panicking_on_drop());
+    d.push_back(tracker.new_item(3));
+    d.push_back(tracker.new_item(4));
+    catch(|| drop(d.drain(1..4)));
+    assert_eq!(deque_values(&d), [0, 4]);
+    assert_eq!(tracker.dropped(), 3);
+    drop(d);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn deque_drain_leaked_forgets_range() {
+    let tracker = Tracker::default();
+    let mut d = wrapped_deque::<8>(&tracker, 4);
+    core::mem::forget(d.drain(1..3));
+    assert_eq!(deque_values(&d), [0]);
+    drop(d);
+    assert_eq!(tracker.dropped(), 1);
+}
+
+#[test]
+fn deque_into_iter_drops_unyielded() {
+    let tracker = Tracker::default();
+    let d = wrapped_deque::<8>(&tracker, 5);
+    let mut iter = d.into_iter();
+    assert_eq!(iter.next_back().unwrap().value, 4);
+    drop(iter);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn append_moves_ownership() {
+    let tracker = Tracker::default();
+    let mut v = filled::<4>(&tracker, 2);
+    let mut other = filled::<2>(&tracker, 2);
+    v.append(&mut other);
+    assert_eq!(values(&v), [0, 1, 0, 1]);
+    drop(other);
+    assert_eq!(tracker.dropped(), 0);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn append_overflow_keeps_both() {
+    let tracker = Tracker::default();
+    let mut v = filled::<3>(&tracker, 2);
+    let mut other = filled::<2>(&tracker, 2);
+    assert!(v.try_append(&mut other).is_err());
+    catch(|| v.append(&mut other));
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(values(&other), [0, 1]);
+    assert_eq!(tracker.dropped(), 0);
+    drop((v, other));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn resize_capacity_moves_ownership() {
+    let tracker = Tracker::default();
+    let v = filled::<2>(&tracker, 2);
+    let v = v.resize_capacity::<4>();
+    let v = match v.try_resize_capacity::<1>() {
+        Ok(_) => unreachable!(),
+        Err(v) => v,
+    };
+    let v = match v.try_resize_capacity::<2>() {
+        Ok(v) => v,
+        Err(_) => unreachable!(),
+    };
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(tracker.dropped(), 0);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn from_fn_panicking_closure() {
+    let tracker = Tracker::default();
+    catch(|| {
+        ArrayVec::<_, 4>::from_fn(4, |i| {
+            if i == 2 {
+                panic!("from_fn");
+            }
+            tracker.new_item(i as i32)
+        })
+    });
+    assert_eq!(tracker.created.get(), 2);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn take_arrays_move_ownership() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    let front: [Item<'_>; 2] = v.take_front_array().unwrap();
+    let back: [Item<'_>; 2] = v.take_back_array().unwrap();
+    assert_eq!(values(&front), [0, 1]);
+    assert_eq!(values(&back), [4, 5]);
+    assert_eq!(values(&v), [2, 3]);
+    assert!(v.take_back_array::<3>().is_none());
+    assert_eq!(tracker.dropped(), 0);
+    drop((front, back, v));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn into_inner_moves_ownership() {
+    let tracker = Tracker::default();
+    let v = filled::<3>(&tracker, 2);
+    let mut v = match v.into_inner() {
+        Ok(_) => unreachable!(),
+        Err(v) => v,
+    };
+    v.push(tracker.new_item(2));
+    let array = match v.into_inner() {
+        Ok(array) => array,
+        Err(_) => unreachable!(),
+    };
+    assert_eq!(values(&array), [0, 1, 2]);
+    assert_eq!(tracker.dropped(), 0);
+    drop(array);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn resize_drops_truncated_and_value() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    v.resize(2, tracker.new_item(9));
+    assert_eq!(values(&v), [0, 1]);
+    assert_eq!(tracker.alive(), 2);
+    v.resize(4, tracker.new_item(9));
+    assert_eq!(values(&v), [0, 1, 9, 9]);
+    match v.try_resize(9, tracker.new_item(7)) {
+        Ok(()) => panic!("resized beyond capacity"),
+        Err(err) => assert_eq!(err.element().value, 7),
+    }
+    assert_eq!(tracker.alive(), 4);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn resize_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 1);
+    catch(|| v.resize(4, tracker.new_item(9).panicking_on_clone()));
+    assert_eq!(values(&v), [0]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn extend_from_within_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 2);
+    v.push(tracker.new_item(2).panicking_on_clone());
+    catch(|| v.extend_from_within(..));
+    assert_eq!(values(&v), [0, 1, 2, 0, 1]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn insert_from_slice_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut source = filled::<8>(&tracker, 2);
+    source.push(tracker.new_item(2).panicking_on_clone());
+    let mut v = filled::<6>(&tracker, 3);
+    catch(|| v.insert_from_slice(1, &source));
+    assert_eq!(values(&v), [0, 1, 2]);
+    assert!(v.try_insert_slice(1, &source[..2]).is_ok());
+    assert_eq!(values(&v), [0, 0, 1, 1, 2]);
+    match v.try_insert_slice(0, &source[..]) {
+        Ok(()) => panic!("inserted beyond capacity"),
+        Err(err) => assert_eq!(err.element().len(), 3),
+    }
+    drop((v, source));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn extract_if_yields_removed() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    let removed: Vec<_> = v.extract_if(1..5, |item| item.value % 2 == 0).collect();
+    assert_eq!(values(&removed), [2, 4]);
+    assert_eq!(values(&v), [0, 1, 3, 5]);
+    assert_eq!(tracker.dropped(), 0);
+    drop((v, removed));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn extract_if_dropped_early_keeps_rest() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    let mut iter = v.extract_if(.., |item| item.value % 2 == 1);
+    assert_eq!(iter.next().unwrap().value, 1);
+    drop(iter);
+    assert_eq!(values(&v), [0, 2, 3, 4, 5]);
+    assert_eq!(tracker.dropped(), 1);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn extract_if_leaked_forgets_elements() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 4);
+    let mut iter = v.extract_if(.., |item| item.value == 1);
+    assert_eq!(iter.next().unwrap().value, 1);
+    std::mem::forget(iter);
+    assert!(v.is_empty());
+    drop(v);
+    assert_eq!(tracker.dropped(), 1);
+}
+
+#[test]
+fn extract_if_panicking_predicate() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 6);
+    catch(|| {
+        v.extract_if(.., |item| {
+            if item.value == 3 {
+                panic!("predicate");
+            }
+            item.value % 2 == 1
+        })
+        .for_each(drop)
+    });
+    assert_eq!(values(&v), [0, 2, 3, 4, 5]);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn retain_mut_modifies_kept() {
+    let tracker = Tracker::default();
+    let mut v = filled::<8>(&tracker, 5);
+    v.retain_mut(|item| {
+        item.value *= 10;
+        item.value >= 20
+    });
+    assert_eq!(values(&v), [20, 30, 40]);
+    assert_eq!(tracker.dropped(), 2);
+    drop(v);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn fill_with_prefix_commits_returned_prefix() {
+    let tracker = Tracker::default();
+    let source = filled::<3>(&tracker, 3);
+    let mut v = filled::<8>(&tracker, 1);
+    let n = v.fill_with_prefix(|spare| spare[..2].write_clone_of_slice(&source[1..]));
+    assert_eq!(n, 2);
+    assert_eq!(values(&v), [0, 1, 2]);
+    catch(|| v.fill_with_prefix(|spare| spare[1..2].write_clone_of_slice(&source[..1])));
+    assert_eq!(values(&v), [0, 1, 2]);
+    drop((v, source));
+    // The element written outside the prefix is leaked.
+    assert_eq!(tracker.alive(), 1);
+}
+
+#[test]
+fn slab_drops_occupied_entries() {
+    let tracker = Tracker::default();
+    {
+        let mut slab: ArraySlab<Item<'_>, 4> = ArraySlab::new();
+        let keys: Vec<_> = (0..4)
+            .map(|i| match slab.insert(tracker.new_item(i)) {
+                Ok(key) => key,
+                Err(_) => unreachable!(),
+            })
+            .collect();
+        match slab.insert(tracker.new_item(4)) {
+            Ok(_) => unreachable!(),
+            Err(item) => assert_eq!(item.value, 4),
+        }
+        assert_eq!(tracker.dropped(), 1);
+        drop(slab.remove(keys[1]));
+        assert!(slab.remove(keys[1]).is_none());
+        slab.retain(|_, item| item.value != 2);
+        assert_eq!(tracker.dropped(), 3);
+        assert_eq!(tracker.alive(), 2);
+    }
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn slab_into_iter_drops_rest() {
+    let tracker = Tracker::default();
+    let mut slab: ArraySlab<Item<'_>, 4> = ArraySlab::new();
+    for i in 0..3 {
+        let _ = slab.insert(tracker.new_item(i));
+    }
+    let mut iter = slab.into_iter();
+    assert_eq!(iter.next().map(|(_, item)| item.value), Some(0));
+    assert_eq!(tracker.dropped(), 1);
+    drop(iter);
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn arena_drops_values() {
+    let tracker = Tracker::default();
+    {
+        let mut arena: ArrayArena<Item<'_>, 4> = ArrayArena::new();
+        let handles: Vec<_> = (0..4)
+            .map(|i| match arena.insert(tracker.new_item(i)) {
+                Ok(handle) => handle,
+                Err(_) => unreachable!(),
+            })
+            .collect();
+        drop(arena.remove(handles[0]));
+        arena.retain(|_, item| item.value != 1);
+        assert_eq!(tracker.dropped(), 2);
+        let mut drain = arena.drain();
+        assert_eq!(drain.next().map(|(_, item)| item.value), Some(2));
+        drop(drain);
+        assert_eq!(tracker.alive(), 0);
+        let _ = arena.insert(tracker.new_item(5));
+    }
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn arena_panicking_drop_drops_rest() {
+    let tracker = Tracker::default();
+    let mut arena: ArrayArena<Item<'_>, 4> = ArrayArena::new();
+    for i in 0..4 {
+        let mut item = tracker.new_item(i);
+        if i == 1 {
+            item = item.panicking_on_drop();
+        }
+        let _ = arena.insert(item);
+    }
+    catch(|| drop(arena));
+    assert_eq!(tracker.alive(), 0);
+}
+
+#[test]
+fn arena_panicking_clone() {
+    let tracker = Tracker::default();
+    let mut arena: ArrayArena<Item<'_>, 4> = ArrayArena::new();
+    for i in 0..3 {
+        let mut item = tracker.new_item(i);
+        if i == 2 {
+            item = item.panicking_on_clone();
+        }
+        let _ = arena.insert(item);
+    }
+    catch(|| drop(arena.clone()));
+    assert_eq!(tracker.alive(), 3);
+    drop(arena);
+    assert_eq!(tracker.alive(), 0);
+}
@@ -0,0 +1,65 @@
+use stack_based_vec::{ArrayVec, CapacityError};
+
+#[test]
+fn capacity_error_keeps_element() {
+    let mut v: ArrayVec<String, 1> = ArrayVec::new();
+    v.push("a".to_string());
+    let err = v.try_push("b".to_string()).unwrap_err();
+    assert_eq!(err.to_string(), "insufficient capacity");
+    assert_eq!(format!("{:?}", err), "CapacityError: insufficient capacity");
+    assert_eq!(err.clone().simplify(), CapacityError::new(()));
+    assert_eq!(err.element(), "b");
+}
+
+#[test]
+fn out_of_bounds_error() {
+    let mut v = ArrayVec::from_array([1, 2, 3]);
+    let err = v.try_remove(3).unwrap_err();
+    assert_eq!(err.to_string(), "index out of bounds");
+    assert_eq!(v.try_swap_remove(3), Err(err));
+    assert_eq!(v.try_split_off(4).unwrap_err(), err);
+    assert_eq!(v.try_drain(..=usize::MAX).unwrap_err(), err);
+    assert_eq!(v.try_splice(4.., None).unwrap_err(), err);
+    assert_eq!(v, [1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "removal index out of bounds")]
+fn remove_out_of_bounds() {
+    ArrayVec::from_array([1]).remove(1);
+}
+
+#[test]
+#[should_panic(expected = "insertion index out of bounds")]
+fn insert_out_of_bounds() {
+    ArrayVec::<i32, 4>::new().insert(1, 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn insert_full() {
+    ArrayVec::from_array([1]).insert(0, 0);
+}
+
+#[test]
+#[should_panic(expected = "drain range out of bounds")]
+fn drain_out_of_bounds() {
+    ArrayVec::from_array([1]).drain(1..2);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn extend_from_slice_overflow() {
+    let mut v: ArrayVec<i32, 2> = ArrayVec::new();
+    v.extend_from_slice(&[1, 2, 3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_error() {
+    let err: Box<dyn std::error::Error> = Box::new(CapacityError::new(1));
+    assert_eq!(err.to_string(), "insufficient capacity");
+    let err: Box<dyn std::error::Error> =
+        Box::new(ArrayVec::<i32, 1>::new().try_remove(0).unwrap_err());
+    assert_eq!(err.to_string(), "index out of bounds");
+}
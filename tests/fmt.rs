@@ -0,0 +1,62 @@
+use core::fmt::{self, Write};
+
+use stack_based_vec::{array_format, ArrayString, ArrayVec};
+
+/// "aé€😀" is 1 + 2 + 3 + 4 bytes long.
+const MIXED: &str = "aé€😀";
+
+#[test]
+fn write_exact_capacity() {
+    let mut buf: ArrayVec<u8, 10> = ArrayVec::new();
+    write!(buf, "{}", MIXED).unwrap();
+    assert_eq!(buf, MIXED.as_bytes());
+    assert!(buf.write_str("").is_ok());
+    assert!(buf.write_char('a').is_err());
+    assert_eq!(buf, MIXED.as_bytes());
+}
+
+#[test]
+fn overflow_keeps_char_boundary_prefix() {
+    // Each capacity cuts `MIXED` at a different point; the kept bytes must be
+    // the longest prefix that is valid UTF-8.
+    let expected = ["", "a", "a", "aé", "aé", "aé", "aé€", "aé€", "aé€", "aé€"];
+    for (cap, expected) in expected.iter().enumerate() {
+        let mut buf: ArrayVec<u8, 16> = ArrayVec::new();
+        buf.extend_from_slice(&[b'_'; 16][..16 - cap]);
+        assert_eq!(write!(buf, "{}", MIXED), Err(fmt::Error));
+        let written = core::str::from_utf8(&buf[16 - cap..]).unwrap();
+        assert_eq!(written, *expected, "capacity {}", cap);
+    }
+}
+
+#[test]
+fn overflow_across_pieces() {
+    let (a, e, euro) = ('a', 'é', '€');
+    let mut buf: ArrayVec<u8, 5> = ArrayVec::new();
+    assert!(write!(buf, "{}{}{}", a, e, euro).is_err());
+    assert_eq!(buf, "aé".as_bytes());
+}
+
+#[test]
+fn array_format_boundaries() {
+    let s: ArrayString<10> = array_format!("{}", MIXED).unwrap();
+    assert_eq!(s, MIXED);
+    assert_eq!(s.len(), s.capacity());
+
+    let s: Result<ArrayString<9>, _> = array_format!("{}", MIXED);
+    assert_eq!(s, Err(fmt::Error));
+}
+
+#[test]
+fn array_format_propagates_trait_errors() {
+    struct Failing;
+
+    impl fmt::Display for Failing {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let s: Result<ArrayString<16>, _> = array_format!("{}", Failing);
+    assert!(s.is_err());
+}
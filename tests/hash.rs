@@ -0,0 +1,67 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use proptest::prelude::*;
+use stack_based_vec::ArrayVec;
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn to_array_vec<T: Clone, const N: usize>(slice: &[T]) -> ArrayVec<T, N> {
+    let mut v = ArrayVec::new();
+    v.extend_from_slice(slice);
+    v
+}
+
+proptest! {
+    #[test]
+    fn hash_matches_slice(keys in prop::collection::vec(any::<u32>(), 0..=8)) {
+        let v: ArrayVec<u32, 8> = to_array_vec(&keys);
+        prop_assert_eq!(hash_of(&v), hash_of(&keys[..]));
+    }
+
+    #[test]
+    fn hash_ignores_capacity(keys in prop::collection::vec(any::<i16>(), 0..=4)) {
+        let small: ArrayVec<i16, 4> = to_array_vec(&keys);
+        let large: ArrayVec<i16, 16> = to_array_vec(&keys);
+        prop_assert_eq!(hash_of(&small), hash_of(&large));
+    }
+
+    #[test]
+    fn hash_map_lookup_by_slice(
+        entries in prop::collection::vec(
+            (prop::collection::vec(any::<u8>(), 0..=6), any::<u32>()),
+            0..32,
+        ),
+        missing in prop::collection::vec(any::<u8>(), 0..=6),
+    ) {
+        let mut map: HashMap<ArrayVec<u8, 6>, u32> = HashMap::new();
+        let mut model: BTreeMap<Vec<u8>, u32> = BTreeMap::new();
+        for (key, value) in &entries {
+            map.insert(to_array_vec(key), *value);
+            model.insert(key.clone(), *value);
+        }
+
+        prop_assert_eq!(map.len(), model.len());
+        for (key, value) in &model {
+            prop_assert_eq!(map.get(&key[..]), Some(value));
+        }
+        prop_assert_eq!(map.get(&missing[..]), model.get(&missing));
+    }
+
+    #[test]
+    fn hash_map_lookup_by_string_slices(
+        keys in prop::collection::vec("[a-z]{0,4}", 0..=3),
+    ) {
+        // Element hashes must also agree for non-`Copy` elements.
+        let key: ArrayVec<String, 3> = to_array_vec(&keys);
+        let mut map = HashMap::new();
+        map.insert(key, ());
+        prop_assert!(map.contains_key(&keys[..]));
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, BufRead, ErrorKind, Read, Write};
+
+use stack_based_vec::{ArrayVec, ArrayVecReader};
+
+#[test]
+fn write_macro() {
+    let mut v: ArrayVec<u8, 16> = ArrayVec::new();
+    let key = "len";
+    write!(v, "{}:{}", key, 12).unwrap();
+    assert_eq!(v, *b"len:12");
+}
+
+#[test]
+fn write_macro_overflow_keeps_prefix() {
+    let mut v: ArrayVec<u8, 4> = ArrayVec::new();
+    let err = write!(v, "{}", 123_456).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(v, *b"1234");
+}
+
+#[test]
+fn partial_writes_at_capacity() {
+    let mut v: ArrayVec<u8, 4> = ArrayVec::new();
+    assert_eq!(v.write(b"abc").unwrap(), 3);
+    assert_eq!(v.write(b"de").unwrap(), 1);
+    assert_eq!(v.write(b"f").unwrap(), 0);
+    assert_eq!(v.write(b"").unwrap(), 0);
+    assert_eq!(v, *b"abcd");
+
+    let mut full: ArrayVec<u8, 2> = ArrayVec::from_array(*b"ab");
+    assert!(full.write_all(b"").is_ok());
+    assert_eq!(
+        full.write_all(b"c").unwrap_err().kind(),
+        ErrorKind::WriteZero
+    );
+}
+
+#[test]
+fn copy_between_array_vecs() {
+    let mut reader = ArrayVecReader::new(ArrayVec::<u8, 8>::from_array(*b"payload!"));
+    let mut out: ArrayVec<u8, 8> = ArrayVec::new();
+    assert_eq!(io::copy(&mut reader, &mut out).unwrap(), 8);
+    assert_eq!(out, *b"payload!");
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn copy_into_smaller_vec() {
+    let mut reader = ArrayVecReader::new(ArrayVec::<u8, 8>::from_array(*b"payload!"));
+    let mut out: ArrayVec<u8, 3> = ArrayVec::new();
+    let err = io::copy(&mut reader, &mut out).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(out, *b"pay");
+}
+
+#[test]
+fn reader_read_and_buf_read() {
+    let mut reader: ArrayVecReader<16> = ArrayVec::from_partial_array(*b"one\ntwo\nthree").into();
+    let mut buf = [0; 2];
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf, b"on");
+    assert_eq!(reader.position(), 2);
+
+    let lines: Vec<String> = reader.lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["e", "two", "three"]);
+}
+
+#[test]
+fn reader_read_exact_past_end() {
+    let mut reader = ArrayVecReader::new(ArrayVec::<u8, 2>::from_array(*b"ab"));
+    let mut buf = [0; 3];
+    assert_eq!(
+        reader.read_exact(&mut buf).unwrap_err().kind(),
+        ErrorKind::UnexpectedEof
+    );
+    assert_eq!(reader.position(), 2);
+    assert!(reader.is_empty());
+
+    let mut cursor = io::Cursor::new(*b"ab");
+    assert!(cursor.read_exact(&mut buf).is_err());
+    assert_eq!(cursor.position(), reader.position() as u64);
+}
+
+#[test]
+fn reader_consume_and_into_inner() {
+    let mut reader = ArrayVecReader::new(ArrayVec::<u8, 4>::from_array(*b"abcd"));
+    assert_eq!(reader.fill_buf().unwrap(), b"abcd");
+    reader.consume(3);
+    assert_eq!(reader.remaining(), b"d");
+    reader.consume(10);
+    assert!(reader.is_empty());
+    assert!(reader.into_inner().is_empty());
+}
+
+#[test]
+fn read_from_appends_to_spare_capacity() {
+    let mut input: &[u8] = b"abcdef";
+    let mut v: ArrayVec<u8, 4> = ArrayVec::from_partial_array(*b"x");
+    assert_eq!(v.read_from(&mut input).unwrap(), 3);
+    assert_eq!(v, *b"xabc");
+    assert_eq!(v.read_from(&mut input).unwrap(), 0);
+    assert_eq!(input, b"def");
+
+    v.clear();
+    assert_eq!(v.read_from(&mut input).unwrap(), 3);
+    assert_eq!(v.read_from(&mut input).unwrap(), 0);
+    assert_eq!(v, *b"def");
+}
+
+#[test]
+fn read_from_propagates_errors() {
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(ErrorKind::Other.into())
+        }
+    }
+
+    let mut v: ArrayVec<u8, 4> = ArrayVec::from_partial_array(*b"ab");
+    assert_eq!(
+        v.read_from(&mut Failing).unwrap_err().kind(),
+        ErrorKind::Other
+    );
+    assert_eq!(v, *b"ab");
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "nightly")]
+#![feature(const_convert, const_default, const_trait_impl, trusted_len)]
+
+use core::iter::TrustedLen;
+
+use stack_based_vec::ArrayVec;
+
+const DEFAULT: ArrayVec<i32, 4> = Default::default();
+
+const FROM: ArrayVec<i32, 3> = ArrayVec::from([1, 2, 3]);
+
+const FROM_LEN: usize = {
+    let v = ArrayVec::from_array([1, 2, 3]);
+    let len = (*v).len();
+    core::mem::forget(v);
+    len
+};
+
+fn assert_trusted_len<I: TrustedLen>(_: &I) {}
+
+#[test]
+fn const_trait_impls() {
+    assert!(DEFAULT.is_empty());
+    assert_eq!(FROM, [1, 2, 3]);
+    assert_eq!(FROM_LEN, 3);
+}
+
+#[test]
+fn iterators_are_trusted_len() {
+    let mut v = ArrayVec::from_array([1, 2, 3]);
+    assert_trusted_len(&v.drain(..2));
+    assert_trusted_len(&v.into_iter());
+}
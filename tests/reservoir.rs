@@ -0,0 +1,64 @@
+use stack_based_vec::{Reservoir, Rng};
+
+/// A small deterministic generator, so the tests need no rand dependency.
+struct SplitMix64(u64);
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[test]
+fn keeps_everything_until_full() {
+    let mut sample: Reservoir<u8, 4, _> = Reservoir::new(SplitMix64(1));
+    assert!(sample.is_empty());
+    sample.extend(0..3);
+    assert_eq!(sample.as_slice(), [0, 1, 2]);
+    assert_eq!(sample.push(3), None);
+    assert!(sample.push(4).is_some());
+    assert_eq!((sample.len(), sample.seen()), (4, 5));
+}
+
+#[test]
+fn returns_each_rejected_item_once() {
+    let mut sample: Reservoir<u32, 5, _> = Reservoir::new(SplitMix64(7));
+    let mut rejected: Vec<u32> = (0..100).filter_map(|x| sample.push(x)).collect();
+    let (kept, _) = sample.into_parts();
+    rejected.extend(kept.iter().copied());
+    rejected.sort_unstable();
+    assert!(rejected.iter().copied().eq(0..100));
+}
+
+#[test]
+fn zero_capacity() {
+    let mut sample: Reservoir<u8, 0, _> = Reservoir::new(|| 0);
+    assert_eq!(sample.push(1), Some(1));
+    assert_eq!(sample.seen(), 1);
+    assert!(sample.into_vec().is_empty());
+}
+
+#[test]
+fn sample_is_uniform() {
+    const ITEMS: usize = 20;
+    const ROUNDS: usize = 20_000;
+
+    let mut rng = SplitMix64(42);
+    let mut counts = [0usize; ITEMS];
+    for _ in 0..ROUNDS {
+        let mut sample: Reservoir<usize, 5, _> = Reservoir::new(|| rng.next_u64());
+        sample.extend(0..ITEMS);
+        for &x in sample.as_slice() {
+            counts[x] += 1;
+        }
+    }
+    // Each item is expected ROUNDS * 5 / ITEMS = 5000 times, with a standard
+    // deviation of about 61.
+    for &count in &counts {
+        assert!((4700..5300).contains(&count), "{:?}", counts);
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use stack_based_vec::ArrayVec;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+struct Config {
+    name: String,
+    ports: ArrayVec<u16, 4>,
+}
+
+fn config() -> Config {
+    Config {
+        name: "edge".to_owned(),
+        ports: ArrayVec::from_partial_array([80, 443]),
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let json = serde_json::to_string(&config()).unwrap();
+    assert_eq!(json, r#"{"name":"edge","ports":[80,443]}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config());
+}
+
+#[test]
+fn json_matches_slice() {
+    let v = ArrayVec::<_, 3>::from_array([1, 2, 3]);
+    assert_eq!(
+        serde_json::to_value(&v).unwrap(),
+        serde_json::to_value(&[1, 2, 3][..]).unwrap()
+    );
+}
+
+#[test]
+fn json_too_long() {
+    let err = serde_json::from_str::<Config>(r#"{"name":"edge","ports":[1,2,3,4,5]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 5, expected a sequence of at most 4 elements"));
+}
+
+#[test]
+fn json_full_and_empty() {
+    let v: ArrayVec<u8, 2> = serde_json::from_str("[1, 2]").unwrap();
+    assert_eq!(v, [1, 2]);
+    let v: ArrayVec<u8, 0> = serde_json::from_str("[]").unwrap();
+    assert!(v.is_empty());
+}
+
+#[test]
+fn postcard_round_trip() {
+    let bytes = postcard::to_allocvec(&config()).unwrap();
+    assert_eq!(postcard::from_bytes::<Config>(&bytes).unwrap(), config());
+}
+
+#[test]
+fn postcard_too_long() {
+    // postcard prefixes sequences with their length, so this fails before
+    // reading any elements.
+    let bytes = postcard::to_allocvec(&[1u16, 2, 3, 4, 5][..]).unwrap();
+    assert!(postcard::from_bytes::<ArrayVec<u16, 4>>(&bytes).is_err());
+    assert!(postcard::from_bytes::<ArrayVec<u16, 5>>(&bytes).is_ok());
+}
+
+#[test]
+fn json_too_long_owned_elements() {
+    // The elements read before the overflow are dropped with the vector.
+    let err = serde_json::from_str::<ArrayVec<String, 1>>(r#"["a", "b"]"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length 2, expected a sequence of at most 1 element at line 1 column 10"
+    );
+}
+
+#[test]
+fn size_hint_reports_actual_length() {
+    use serde::de::{value::SeqDeserializer, IntoDeserializer};
+
+    let deserializer: SeqDeserializer<_, serde::de::value::Error> =
+        vec![0u8; 1000].into_deserializer();
+    let err = ArrayVec::<u8, 4>::deserialize(deserializer).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length 1000, expected a sequence of at most 4 elements"
+    );
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "alloc")]
+
+use std::rc::Rc;
+
+use stack_based_vec::{ArrayVec, SmallVec};
+
+#[test]
+fn push_spills_once_full() {
+    let mut v: SmallVec<i32, 3> = SmallVec::new();
+    for i in 0..3 {
+        v.push(i);
+        assert!(!v.spilled());
+    }
+    v.push(3);
+    assert!(v.spilled());
+    assert!(v.capacity() >= 4);
+    assert_eq!(v, [0, 1, 2, 3]);
+}
+
+#[test]
+fn insert_spills_at_position() {
+    let mut v: SmallVec<i32, 3> = (0..3).collect();
+    v.insert(1, 9);
+    assert!(v.spilled());
+    assert_eq!(v, [0, 9, 1, 2]);
+    assert_eq!(v.remove(1), 9);
+    assert_eq!(v.swap_remove(0), 0);
+    assert_eq!(v, [2, 1]);
+}
+
+#[test]
+#[should_panic(expected = "insertion index out of bounds")]
+fn insert_out_of_bounds() {
+    let mut v: SmallVec<i32, 3> = SmallVec::new();
+    v.insert(1, 0);
+}
+
+#[test]
+fn shrink_to_inline_round_trip() {
+    let mut v: SmallVec<i32, 2> = SmallVec::from(vec![1, 2, 3]);
+    assert!(v.spilled());
+    assert!(v.shrink_to_inline().is_err());
+    assert_eq!(v, [1, 2, 3]);
+
+    v.truncate(2);
+    assert!(v.shrink_to_inline().is_ok());
+    assert!(!v.spilled());
+    assert_eq!(v, [1, 2]);
+}
+
+#[test]
+fn into_vec_and_into_inline() {
+    let inline: SmallVec<i32, 4> = ArrayVec::from_partial_array([1, 2]).into();
+    assert_eq!(inline.clone().into_vec(), [1, 2]);
+    assert_eq!(inline.into_inline().unwrap(), [1, 2]);
+
+    let heap: SmallVec<i32, 1> = (1..=2).collect();
+    let heap = heap.into_inline().unwrap_err();
+    assert_eq!(Vec::from(heap), [1, 2]);
+}
+
+#[test]
+fn drain_both_modes() {
+    let mut v: SmallVec<i32, 4> = (0..4).collect();
+    assert!(v.drain(1..3).rev().eq([2, 1]));
+    assert_eq!(v, [0, 3]);
+
+    let mut v: SmallVec<i32, 2> = (0..4).collect();
+    assert_eq!(v.drain(..2).len(), 2);
+    assert_eq!(v, [2, 3]);
+}
+
+#[test]
+fn splice_inline_fits() {
+    let mut v: SmallVec<i32, 4> = (0..3).collect();
+    assert!(v.splice(..1, [7, 8]).eq([0]));
+    assert!(!v.spilled());
+    assert_eq!(v, [7, 8, 1, 2]);
+}
+
+#[test]
+fn splice_overflow_spills_in_order() {
+    for tail in 0..3 {
+        let mut v: SmallVec<i32, 4> = (0..4).collect();
+        let start = 1;
+        let end = 4 - tail;
+        let mut expected: Vec<i32> = (0..4).collect();
+        let removed: Vec<_> = expected.splice(start..end, 10..16).collect();
+
+        assert!(v.splice(start..end, 10..16).eq(removed));
+        assert!(v.spilled());
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+fn splice_drops_balance() {
+    let rc = Rc::new(());
+    let mut v: SmallVec<Rc<()>, 2> = (0..2).map(|_| rc.clone()).collect();
+    drop(v.splice(1.., (0..5).map(|_| rc.clone())));
+    assert_eq!(v.len(), 6);
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(v);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn retain_dedup_split_off() {
+    let mut v: SmallVec<i32, 8> = [1, 1, 2, 3, 3, 4].iter().copied().collect();
+    v.dedup();
+    v.retain(|&x| x != 2);
+    assert_eq!(v, [1, 3, 4]);
+
+    let tail = v.split_off(1);
+    assert!(!tail.spilled());
+    assert_eq!((v.as_slice(), tail.as_slice()), (&[1][..], &[3, 4][..]));
+
+    let mut v: SmallVec<i32, 1> = (0..3).collect();
+    v.extend_from_slice(&[3, 4]);
+    let tail = v.split_off(1);
+    assert!(tail.spilled());
+    assert_eq!(tail, [1, 2, 3, 4]);
+}
+
+#[test]
+fn resize_and_retain_mut_both_modes() {
+    let mut v: SmallVec<i32, 4> = (0..2).collect();
+    v.resize(4, 9);
+    assert!(!v.spilled());
+    assert_eq!(v, [0, 1, 9, 9]);
+
+    let mut next = 10;
+    v.resize_with(6, || {
+        next += 1;
+        next
+    });
+    assert!(v.spilled());
+    assert_eq!(v, [0, 1, 9, 9, 11, 12]);
+
+    v.retain_mut(|x| {
+        *x += 1;
+        *x % 2 == 0
+    });
+    assert_eq!(v, [2, 10, 10, 12]);
+    v.resize(1, 0);
+    assert_eq!(v, [2]);
+
+    let mut v: SmallVec<i32, 4> = (0..4).collect();
+    v.retain_mut(|x| {
+        *x *= 3;
+        *x != 3
+    });
+    assert!(!v.spilled());
+    assert_eq!(v, [0, 6, 9]);
+}
+
+#[test]
+fn into_iter_both_modes() {
+    let v: SmallVec<i32, 4> = (0..3).collect();
+    assert!(v.into_iter().rev().eq([2, 1, 0]));
+
+    let v: SmallVec<i32, 1> = (0..3).collect();
+    let mut iter = v.into_iter();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.as_slice(), [1, 2]);
+}
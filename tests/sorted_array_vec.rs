@@ -0,0 +1,149 @@
+use proptest::prelude::*;
+use stack_based_vec::{ArrayVec, SortedArrayVec};
+
+fn sorted<const N: usize>(values: &[u8]) -> SortedArrayVec<u8, N> {
+    let mut v = SortedArrayVec::new();
+    for &value in values {
+        v.insert(value);
+    }
+    v
+}
+
+/// Counts how many times each value appears in a sorted slice.
+fn counts(values: &[u8]) -> [usize; 256] {
+    let mut counts = [0; 256];
+    for &value in values {
+        counts[usize::from(value)] += 1;
+    }
+    counts
+}
+
+/// Builds the sorted multiset with `f(a_count, b_count)` copies of each value.
+fn combine(a: &[u8], b: &[u8], f: impl Fn(usize, usize) -> usize) -> Vec<u8> {
+    let (a, b) = (counts(a), counts(b));
+    (0..=255u8)
+        .flat_map(|value| {
+            std::iter::repeat_n(value, f(a[usize::from(value)], b[usize::from(value)]))
+        })
+        .collect()
+}
+
+#[test]
+fn insert_keeps_equal_elements_in_order() {
+    let mut v: SortedArrayVec<(u8, char), 4> = SortedArrayVec::new();
+    v.insert((1, 'a'));
+    v.insert((0, 'b'));
+    v.insert((1, 'c'));
+    assert_eq!(v, [(0, 'b'), (1, 'a'), (1, 'c')]);
+}
+
+#[test]
+fn default_does_not_require_default_elements() {
+    #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+    struct NoDefault(u8);
+
+    let mut v: SortedArrayVec<NoDefault, 2> = Default::default();
+    v.insert(NoDefault(1));
+    assert_eq!(v.len(), 1);
+}
+
+#[test]
+fn try_insert_full() {
+    let mut v: SortedArrayVec<u8, 2> = sorted(&[3, 1]);
+    assert_eq!(v.try_insert(2).unwrap_err().element(), 2);
+    assert_eq!(v, [1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn insert_full() {
+    let mut v: SortedArrayVec<u8, 2> = sorted(&[3, 1]);
+    v.insert(2);
+}
+
+#[test]
+fn range_bounds() {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    let v: SortedArrayVec<u8, 8> = sorted(&[1, 2, 2, 4, 5]);
+    assert_eq!(v.range((Excluded(2), Unbounded)), &[4, 5]);
+    assert_eq!(v.range((Excluded(1), Included(4))), &[2, 2, 4]);
+    assert_eq!(v.range(..=0), &[]);
+    assert_eq!(v.range(..), &[1, 2, 2, 4, 5]);
+}
+
+#[test]
+fn borrowed_lookups() {
+    use std::ops::Bound::{Excluded, Included};
+
+    let mut v: SortedArrayVec<String, 4> = SortedArrayVec::new();
+    v.insert("b".to_string());
+    v.insert("a".to_string());
+    assert!(v.contains("a"));
+    assert_eq!(v.range::<str, _>((Included("a"), Excluded("b"))), ["a"]);
+    assert_eq!(v.remove_value("b").as_deref(), Some("b"));
+    assert_eq!(v, ["a"]);
+}
+
+#[test]
+fn set_operations_overflow() {
+    let a: SortedArrayVec<u8, 4> = sorted(&[1, 2, 3]);
+    let b: SortedArrayVec<u8, 4> = sorted(&[3, 4]);
+    assert!(a.try_union::<4, 3>(&b).is_err());
+    assert!(a.try_merge::<4, 4>(&b).is_err());
+    assert!(a.try_intersection::<4, 0>(&b).is_err());
+    assert_eq!(a.try_intersection::<4, 1>(&b).unwrap(), [3]);
+}
+
+#[test]
+fn conversions() {
+    let v: SortedArrayVec<u8, 4> = ArrayVec::from_array([3, 1, 2]).resize_capacity().into();
+    let v: ArrayVec<u8, 4> = v.into();
+    assert_eq!(v, [1, 2, 3]);
+}
+
+proptest! {
+    #[test]
+    fn insert_and_remove_match_model(
+        ops in prop::collection::vec((any::<bool>(), 0..16u8), 0..64),
+    ) {
+        let mut v: SortedArrayVec<u8, 8> = SortedArrayVec::new();
+        let mut model = Vec::new();
+        for (insert, value) in ops {
+            if insert {
+                if model.len() < 8 {
+                    let idx = v.insert(value);
+                    model.insert(idx, value);
+                } else {
+                    prop_assert!(v.try_insert(value).is_err());
+                }
+            } else {
+                let removed = v.remove_value(&value);
+                let expected = model.iter().position(|&e| e == value).map(|idx| model.remove(idx));
+                prop_assert_eq!(removed, expected);
+            }
+            prop_assert!(model.windows(2).all(|w| w[0] <= w[1]));
+            prop_assert_eq!(&v[..], &model[..]);
+            for value in 0..16 {
+                prop_assert_eq!(v.contains(&value), model.contains(&value));
+            }
+        }
+    }
+
+    #[test]
+    fn set_operations_match_model(
+        a in prop::collection::vec(0..8u8, 0..=8),
+        b in prop::collection::vec(0..8u8, 0..=8),
+    ) {
+        let (a, b): (SortedArrayVec<u8, 8>, SortedArrayVec<u8, 8>) = (sorted(&a), sorted(&b));
+
+        let merged: SortedArrayVec<u8, 16> = a.merge(&b);
+        prop_assert_eq!(&merged[..], &combine(&a, &b, |x, y| x + y)[..]);
+
+        let union: SortedArrayVec<u8, 16> = a.union(&b);
+        prop_assert_eq!(&union[..], &combine(&a, &b, usize::max)[..]);
+
+        let intersection: SortedArrayVec<u8, 8> = a.intersection(&b);
+        prop_assert_eq!(&intersection[..], &combine(&a, &b, usize::min)[..]);
+    }
+}
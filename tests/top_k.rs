@@ -0,0 +1,61 @@
+use std::cmp::Reverse;
+
+use proptest::prelude::*;
+use stack_based_vec::{Min, TopK};
+
+#[test]
+fn keeps_greatest_and_reports_evictions() {
+    let mut top: TopK<u32, 3> = TopK::new();
+    assert!(top.is_empty());
+    for x in [5, 1, 8] {
+        assert_eq!(top.push(x), None);
+    }
+    assert!(top.is_full());
+    assert_eq!(top.min(), Some(&1));
+    assert_eq!(top.push(0), Some(0));
+    assert_eq!(top.push(6), Some(1));
+    assert_eq!(top.min(), Some(&5));
+    assert_eq!(format!("{:?}", TopK::<u8, 1>::new()), "[]");
+    assert_eq!(top.into_sorted_vec(), [8, 6, 5]);
+}
+
+#[test]
+fn equal_keys_keep_first() {
+    let mut top = TopK::<_, 2, _>::by_key(|&(key, _): &(u8, char)| key);
+    top.extend(
+        [(1, 'a'), (2, 'b'), (2, 'c'), (1, 'd'), (2, 'e')]
+            .iter()
+            .copied(),
+    );
+    assert_eq!(top.into_sorted_vec(), [(2, 'b'), (2, 'c')]);
+}
+
+#[test]
+fn smallest_with_min_or_reverse() {
+    let data = [7, 3, 9, 1, 4];
+    let mut min = TopK::<_, 2, _>::with_comparator(Min);
+    min.extend(data.iter().copied());
+    assert_eq!(min.into_sorted_vec(), [1, 3]);
+
+    let reverse: TopK<_, 2> = data.iter().map(|&x| Reverse(x)).collect();
+    assert_eq!(reverse.into_sorted_vec(), [Reverse(1), Reverse(3)]);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut top: TopK<u8, 0> = TopK::new();
+    assert_eq!(top.push(1), Some(1));
+    assert!(top.into_vec().is_empty());
+}
+
+proptest! {
+    #[test]
+    fn matches_sorted_model(items in prop::collection::vec(any::<u16>(), 0..64)) {
+        let top: TopK<u16, 8> = items.iter().copied().collect();
+        let mut model = items;
+        model.sort_unstable_by(|a, b| b.cmp(a));
+        model.truncate(8);
+        let sorted = top.into_sorted_vec();
+        prop_assert_eq!(sorted.as_slice(), model.as_slice());
+    }
+}